@@ -0,0 +1,34 @@
+#![no_std]
+
+// Nothing here runs. Building this crate for a target without `std`, 
+// such as `thumbv7em-none-eabihf`, proves the core push/pop paths of rsds 
+// compile against `core` and `alloc` alone.
+
+use rsds::deque::{Deque, DequeFullError};
+use rsds::queue::{Queue, QueueFullError};
+use rsds::stack::{Stack, StackFullError};
+
+pub fn stack_push_pop(val: u32) -> Result<Option<u32>, StackFullError> {
+    let mut stack = Stack::new(2);
+
+    stack.push(val)?;
+
+    Ok(stack.pop())
+}
+
+pub fn queue_enqueue_dequeue(val: u32) -> Result<Option<u32>, QueueFullError> {
+    let mut queue = Queue::new(2);
+
+    queue.enqueue(val)?;
+
+    Ok(queue.dequeue())
+}
+
+pub fn deque_push_pop(val: u32) -> Result<Option<u32>, DequeFullError> {
+    let mut d = Deque::new(2);
+
+    d.push_back(val)?;
+    d.push_front(val)?;
+
+    Ok(d.pop_back())
+}
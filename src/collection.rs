@@ -0,0 +1,152 @@
+/// Common operations shared by the bounded collections in this crate. 
+/// `T` is the type of element held by the collection, which lets generic 
+/// code bound on `Collection<T>` for a specific element type.
+/// 
+/// The trait is object safe, so a `&dyn Collection<T>` can refer to any 
+/// of the collections.
+/// ```
+/// use rsds::collection::Collection;
+/// use rsds::queue::Queue;
+/// use rsds::stack::Stack;
+/// 
+/// fn drain_all<C: Collection<u32>>(c: &mut C) {
+///     c.clear();
+/// }
+/// 
+/// let mut s = Stack::<u32>::new(5);
+/// s.push(1u32);
+/// drain_all(&mut s);
+/// assert!(s.is_empty());
+/// 
+/// let mut q = Queue::<u32>::new(5);
+/// q.enqueue(1u32);
+/// drain_all(&mut q);
+/// assert!(q.is_empty());
+/// ```
+pub trait Collection<T> {
+    /// Returns the number of elements in the collection.
+    fn len(&self) -> usize;
+
+    /// Returns the max capacity of the collection.
+    fn capacity(&self) -> usize;
+
+    /// Returns `true` if the collection contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if adding another element will fail with the 
+    /// collection's full error. By default this is when the collection is 
+    /// at capacity; a collection that grows instead of failing returns 
+    /// `false`.
+    fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Removes all elements from the collection without changing its 
+    /// capacity.
+    fn clear(&mut self);
+}
+
+/// How a growable collection picks its new capacity when a value is added 
+/// while it is full.
+/// ```
+/// use rsds::collection::GrowthPolicy;
+/// use rsds::stack::Stack;
+/// 
+/// let mut s = Stack::<u32>::new_growable_with(2, GrowthPolicy::Fixed(3));
+/// 
+/// for i in 0..3 {
+///     assert_eq!(s.push(i), Ok(()));
+/// }
+/// 
+/// assert_eq!(s.capacity(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GrowthPolicy {
+    /// Double the capacity.
+    #[default]
+    Double,
+    /// Add a fixed number of slots. Adding zero is treated as adding one.
+    Fixed(usize),
+    /// Grow the capacity by half.
+    OnePointFive,
+}
+
+impl GrowthPolicy {
+    /// Returns the capacity to grow to from `capacity`. The result is 
+    /// always at least one larger than `capacity`.
+    pub(crate) fn next_capacity(&self, capacity: usize) -> usize {
+        let grown = match self {
+            GrowthPolicy::Double => capacity.saturating_mul(2),
+            GrowthPolicy::Fixed(n) => capacity.saturating_add(*n),
+            GrowthPolicy::OnePointFive => capacity.saturating_add(capacity / 2),
+        };
+
+        grown.max(capacity + 1)
+    }
+}
+
+#[cfg(test)]
+mod test_collection {
+    use crate::collection::*;
+    use crate::deque::Deque;
+    use crate::queue::Queue;
+    use crate::stack::Stack;
+
+    fn fill_and_clear<C: Collection<u32>>(c: &mut C, fill: impl Fn(&mut C, u32)) {
+        let capacity = c.capacity();
+
+        assert!(Collection::is_empty(c));
+        assert!(!Collection::is_full(c));
+
+        for i in 0..capacity as u32 {
+            fill(c, i);
+        }
+
+        assert_eq!(Collection::len(c), capacity);
+        assert!(Collection::is_full(c));
+
+        Collection::clear(c);
+
+        assert!(Collection::is_empty(c));
+        assert_eq!(c.capacity(), capacity);
+    }
+
+    #[test]
+    fn collection_generic_over_stack() {
+        let mut stack = Stack::new(3);
+
+        fill_and_clear(&mut stack, |s, i| assert!(s.push(i).is_ok()));
+    }
+
+    #[test]
+    fn collection_generic_over_queue() {
+        let mut queue = Queue::new(3);
+
+        fill_and_clear(&mut queue, |q, i| assert!(q.enqueue(i).is_ok()));
+    }
+
+    #[test]
+    fn collection_generic_over_deque() {
+        let mut d = Deque::new(3);
+
+        fill_and_clear(&mut d, |d, i| assert!(d.push_back(i).is_ok()));
+    }
+
+    #[test]
+    fn collection_is_object_safe() {
+        let mut stack = Stack::new(3);
+        let mut queue = Queue::new(3);
+
+        assert!(stack.push(1u32).is_ok());
+        assert!(queue.enqueue(1u32).is_ok());
+        assert!(queue.enqueue(2u32).is_ok());
+
+        let collections: Vec<&dyn Collection<u32>> = vec![&stack, &queue];
+        let lens: Vec<usize> = collections.iter().map(|c| c.len()).collect();
+
+        assert_eq!(lens, vec![1, 2]);
+    }
+}
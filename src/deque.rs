@@ -1,16 +1,34 @@
 
-use std::vec::Vec;
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use crate::collection::Collection;
+use crate::error::AllocError;
 
 #[derive(Debug, PartialEq)]
 pub struct DequeFullError;
 
-pub struct Deque<T: Clone> {
+impl fmt::Display for DequeFullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deque is full")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DequeFullError {}
+
+#[derive(Clone)]
+pub struct Deque<T> {
     data: Vec<Option<T>>,
     count: usize,
     tail: usize,
 }
 
-impl<T: Clone> Deque<T> {
+impl<T> Deque<T> {
     pub fn new(size: usize) -> Self {
         let mut d = Deque {
             data: Vec::with_capacity(size),
@@ -25,11 +43,51 @@ impl<T: Clone> Deque<T> {
         d
     }
 
+    pub fn try_new(size: usize) -> Result<Self, AllocError> {
+        let mut data = Vec::<Option<T>>::new();
+        data.try_reserve_exact(size).map_err(|_| AllocError)?;
+
+        for _ in 0..data.capacity() {
+            data.push(None);
+        }
+
+        Ok(Deque {
+            data,
+            count: 0,
+            tail: 0,
+        })
+    }
+
+    pub fn from_std(vd: VecDeque<T>, capacity: usize) -> Result<Deque<T>, DequeFullError> {
+        if vd.len() > capacity {
+            return Err(DequeFullError);
+        }
+
+        let mut d = Deque::new(capacity);
+
+        for val in vd {
+            d.push_back(val)?;
+        }
+
+        Ok(d)
+    }
+
+    pub fn into_std(mut self) -> VecDeque<T> {
+        let mut vd = VecDeque::with_capacity(self.count);
+
+        while let Some(val) = self.pop_front() {
+            vd.push_back(val);
+        }
+
+        vd
+    }
+
     pub fn push_front(&mut self, val: T) -> Result<(), DequeFullError> {
         if self.count == self.data.capacity() {
             Err(DequeFullError)
         } else {
             let head = (self.tail + self.data.capacity() - self.count) % self.data.capacity();
+            let head = (head + self.data.capacity() - 1) % self.data.capacity();
             
             self.data[head] = Some(val);
             self.count += 1;
@@ -42,22 +100,64 @@ impl<T: Clone> Deque<T> {
         if self.count == self.data.capacity() {
             Err(DequeFullError)
         } else {
-            self.tail = (self.tail + self.data.capacity() - 1) % self.data.capacity(); 
             self.data[self.tail] = Some(val);
+            self.tail = (self.tail + 1) % self.data.capacity(); 
             self.count += 1;
 
             Ok(())
         }
     }
 
+    pub fn push_front_overwrite(&mut self, val: T) -> Option<T> {
+        if self.data.capacity() == 0 {
+            return Some(val);
+        }
+
+        if self.count == self.data.capacity() {
+            // head and tail share a slot when full, so the slot before 
+            // them holds the back element and becomes the new head
+            self.tail = (self.tail + self.data.capacity() - 1) % self.data.capacity();
+            let evicted = self.data[self.tail].take();
+            self.data[self.tail] = Some(val);
+
+            evicted
+        } else {
+            let head = (self.tail + self.data.capacity() - self.count) % self.data.capacity();
+            let head = (head + self.data.capacity() - 1) % self.data.capacity();
+
+            self.data[head] = Some(val);
+            self.count += 1;
+
+            None
+        }
+    }
+
+    pub fn push_back_overwrite(&mut self, val: T) -> Option<T> {
+        if self.data.capacity() == 0 {
+            return Some(val);
+        }
+
+        let evicted = if self.count == self.data.capacity() {
+            // head and tail share a slot when full
+            self.data[self.tail].take()
+        } else {
+            self.count += 1;
+            None
+        };
+
+        self.data[self.tail] = Some(val);
+        self.tail = (self.tail + 1) % self.data.capacity();
+
+        evicted
+    }
+
     pub fn pop_front(&mut self) -> Option<T> {
         if self.count == 0 {
             None
         } else {
             let head = (self.tail + self.data.capacity() - self.count) % self.data.capacity();
-            let ret = self.data[head].clone();
+            let ret = self.data[head].take();
             
-            self.data[head] = None;
             self.count -= 1;
 
             ret
@@ -68,10 +168,9 @@ impl<T: Clone> Deque<T> {
         if self.count == 0 {
             None
         } else {
-            let ret = self.data[self.tail].clone();
-            
-            self.data[self.tail] = None;
             self.tail = (self.tail + self.data.capacity() - 1) % self.data.capacity();
+            let ret = self.data[self.tail].take();
+            
             self.count -= 1;
 
             ret
@@ -79,29 +178,442 @@ impl<T: Clone> Deque<T> {
     }
 
     #[must_use]
-    pub fn peek_front(&self) -> &Option<T> {
+    pub fn peek_front(&self) -> Option<&T> {
         if self.count == 0 {
-            &None
+            None
         } else {
             let head = (self.tail + self.data.capacity() - self.count) % self.data.capacity();
     
-            self.data.get(head).unwrap()
+            self.data[head].as_ref()
+        }
+    }
+
+    #[must_use]
+    pub fn peek_back(&self) -> Option<&T> {
+        if self.count == 0 {
+            None
+        } else {
+            let back = (self.tail + self.data.capacity() - 1) % self.data.capacity();
+
+            self.data[back].as_ref()
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.count {
+            None
+        } else {
+            let head = (self.tail + self.data.capacity() - self.count) % self.data.capacity();
+            let slot = (head + index) % self.data.capacity();
+
+            self.data[slot].as_ref()
+        }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.count {
+            None
+        } else {
+            let head = (self.tail + self.data.capacity() - self.count) % self.data.capacity();
+            let slot = (head + index) % self.data.capacity();
+
+            self.data[slot].as_mut()
+        }
+    }
+
+    /// Searches a deque sorted in ascending front to back order for 
+    /// `target`. Returns `Ok` with the logical index of a matching element, 
+    /// counting from the front, or `Err` with the index at which `target` 
+    /// could be inserted to keep the deque sorted. If several elements 
+    /// match, any one of them may be returned. The result is unspecified, 
+    /// but never a panic, if the deque is not sorted.
+    /// ```
+    /// use rsds::deque::Deque;
+    /// 
+    /// let mut d = Deque::new(5);
+    /// 
+    /// d.push_back(20u32);
+    /// d.push_back(30u32);
+    /// d.push_front(10u32);
+    /// 
+    /// assert_eq!(d.binary_search(&20u32), Ok(1));
+    /// assert_eq!(d.binary_search(&25u32), Err(2));
+    /// assert_eq!(d.binary_search(&5u32), Err(0));
+    /// ```
+    pub fn binary_search(&self, target: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        let mut lo = 0;
+        let mut hi = self.count;
+
+        // lo and hi are logical indices from the front
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+
+            match self.get(mid).map(|val| val.cmp(target)) {
+                Some(Ordering::Less) => lo = mid + 1,
+                Some(Ordering::Greater) => hi = mid,
+                Some(Ordering::Equal) => return Ok(mid),
+                // every index below count is live, but stop rather than 
+                // panic should that ever not hold
+                None => break,
+            }
         }
+
+        Err(lo)
+    }
+
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.count, "index {} out of range for deque of length {}", i, self.count);
+        assert!(j < self.count, "index {} out of range for deque of length {}", j, self.count);
+
+        let capacity = self.data.capacity();
+        let head = (self.tail + capacity - self.count) % capacity;
+
+        self.data.swap((head + i) % capacity, (head + j) % capacity);
     }
 
+    /// Returns the occupied slots as two slices. The first slice runs from 
+    /// the front to the end of the buffer and the second holds any 
+    /// elements that wrapped around to the start, so concatenating them 
+    /// yields the elements in front to back order. Every returned slot is 
+    /// `Some`.
     #[must_use]
-    pub fn peek_back(&self) -> &Option<T> {
-       if self.count == 0 {
-            &None
+    pub fn as_slices(&self) -> (&[Option<T>], &[Option<T>]) {
+        if self.count == 0 {
+            return (&[], &[]);
+        }
+
+        let capacity = self.data.capacity();
+        let head = (self.tail + capacity - self.count) % capacity;
+
+        if head + self.count <= capacity {
+            (&self.data[head..head + self.count], &[])
+        } else {
+            (&self.data[head..], &self.data[..head + self.count - capacity])
+        }
+    }
+
+    /// Rotates the internal storage so the front element sits at the start 
+    /// of the buffer and the occupied slots run in front to back order.
+    fn linearize(&mut self) {
+        let capacity = self.data.capacity();
+
+        if self.count > 0 {
+            let head = (self.tail + capacity - self.count) % capacity;
+
+            self.data.rotate_left(head);
+            self.tail = self.count % capacity;
+        }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.count.checked_add(additional).expect("capacity overflow");
+
+        if required <= self.data.capacity() {
+            return;
+        }
+
+        // live elements move to the start so the new slots follow the tail
+        self.linearize();
+        self.data.reserve_exact(required - self.data.len());
+
+        for _ in self.data.len()..self.data.capacity() {
+            self.data.push(None);
+        }
+
+        self.tail = self.count;
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        if self.count == 0 {
+            return;
+        }
+
+        let capacity = self.data.capacity();
+        let head = (self.tail + capacity - self.count) % capacity;
+        let mut kept = 0;
+
+        for i in 0..self.count {
+            // kept elements only ever move towards the head, into slots 
+            // that have already been visited
+            if let Some(val) = self.data[(head + i) % capacity].take() {
+                if f(&val) {
+                    self.data[(head + kept) % capacity] = Some(val);
+                    kept += 1;
+                }
+            }
+        }
+
+        self.count = kept;
+        self.tail = (head + kept) % capacity;
+    }
+
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.count == 0 {
+            return;
+        }
+
+        let capacity = self.data.capacity();
+        let n = n % self.count;
+
+        if self.count == capacity {
+            // no empty slots, so moving the tail moves the head with it
+            self.tail = (self.tail + n) % capacity;
+        } else {
+            for _ in 0..n {
+                let head = (self.tail + capacity - self.count) % capacity;
+
+                self.data[self.tail] = self.data[head].take();
+                self.tail = (self.tail + 1) % capacity;
+            }
+        }
+    }
+
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.count == 0 {
+            return;
+        }
+
+        let capacity = self.data.capacity();
+        let n = n % self.count;
+
+        if self.count == capacity {
+            // no empty slots, so moving the tail moves the head with it
+            self.tail = (self.tail + capacity - n) % capacity;
         } else {
-            self.data.get(self.tail).unwrap()
+            for _ in 0..n {
+                let head = (self.tail + capacity - self.count) % capacity;
+                let head = (head + capacity - 1) % capacity;
+
+                self.tail = (self.tail + capacity - 1) % capacity;
+                self.data[head] = self.data[self.tail].take();
+            }
         }
     }
 
     #[must_use]
+    pub fn contains(&self, val: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|x| x == val)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    #[must_use]
+    #[deprecated(note = "use len()")]
     pub fn size(&self) -> usize {
+        self.len()
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.data.capacity() - self.count
+    }
+
+    pub fn clear(&mut self) {
+        for slot in self.data.iter_mut() {
+            *slot = None;
+        }
+
+        self.count = 0;
+        self.tail = 0;
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.count == self.data.capacity()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        let capacity = self.data.capacity();
+        let head = if self.count == 0 {
+            0
+        } else {
+            (self.tail + capacity - self.count) % capacity
+        };
+
+        (0..self.count).filter_map(move |i| self.data[(head + i) % capacity].as_ref())
+    }
+
+    pub fn cycle_iter(&self) -> impl Iterator<Item = &T> {
+        let capacity = self.data.capacity();
+        let head = if self.count == 0 {
+            0
+        } else {
+            (self.tail + capacity - self.count) % capacity
+        };
+
+        // cycling an empty range ends immediately, so an empty deque does 
+        // not loop forever
+        (0..self.count).cycle().filter_map(move |i| self.data[(head + i) % capacity].as_ref())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let capacity = self.data.capacity();
+        let head = if self.count == 0 {
+            0
+        } else {
+            (self.tail + capacity - self.count) % capacity
+        };
+
+        // live slots run from head and may wrap around to the start, every 
+        // other slot is None
+        let (wrapped, front) = self.data.split_at_mut(head);
+
+        front.iter_mut().chain(wrapped.iter_mut()).filter_map(Option::as_mut)
+    }
+}
+
+impl<T> Default for Deque<T> {
+    /// Creates an empty `Deque` with a max capacity of zero. The `Deque` 
+    /// is full as soon as it is created, so `push_front` and `push_back` 
+    /// return a `DequeFullError` until it is given room with `reserve`.
+    /// ```
+    /// use rsds::deque::{Deque, DequeFullError};
+    /// 
+    /// let mut d = Deque::<u32>::default();
+    /// assert_eq!(d.push_back(1u32), Err(DequeFullError));
+    /// 
+    /// d.reserve(1);
+    /// assert_eq!(d.push_back(1u32), Ok(()));
+    /// ```
+    fn default() -> Self {
+        Deque::new(0)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Deque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Deque<T> {}
+
+impl<T: PartialOrd> PartialOrd for Deque<T> {
+    /// Deques are ordered lexicographically by their elements from front 
+    /// to back. The first differing element decides the order, and if one 
+    /// deque is a prefix of the other the shorter deque is less. Capacity 
+    /// and the internal position of the elements are not compared.
+    /// ```
+    /// use rsds::deque::Deque;
+    /// 
+    /// let mut a = Deque::new(3);
+    /// let mut b = Deque::new(3);
+    /// 
+    /// a.push_back(1u32);
+    /// a.push_back(2u32);
+    /// 
+    /// b.push_back(3u32);
+    /// b.push_front(1u32);
+    /// 
+    /// assert!(a < b);
+    /// 
+    /// b.pop_back();
+    /// assert!(b < a);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for Deque<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for Deque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.count.hash(state);
+
+        for val in self.iter() {
+            val.hash(state);
+        }
+    }
+}
+
+
+impl<T: fmt::Display> fmt::Display for Deque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+
+        for (i, val) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", val)?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Deque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements: Vec<&T> = self.iter().collect();
+
+        f.debug_struct("Deque")
+            .field("size", &self.count)
+            .field("capacity", &self.data.capacity())
+            .field("elements", &elements)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Deque<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let elements: Vec<&T> = self.iter().collect();
+
+        let mut state = serializer.serialize_struct("Deque", 2)?;
+        state.serialize_field("capacity", &self.data.capacity())?;
+        state.serialize_field("elements", &elements)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Deque<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::serde_support::Repr::<T>::deserialize(deserializer)?
+            .build("deque", Deque::try_new, |c, val| c.push_back(val))
+    }
+}
+
+impl<T> Collection<T> for Deque<T> {
+    fn len(&self) -> usize {
         self.count
     }
+
+    fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    fn clear(&mut self) {
+        Deque::clear(self)
+    }
 }
 
 #[cfg(test)]
@@ -120,15 +632,1513 @@ mod test_deque {
         let d = Deque::<u32>::new(5);
 
         for element in &d.data {
-            assert_eq!(element, None);
+            assert_eq!(element, &None);
         } 
     }
 
     #[test]
+    #[allow(deprecated)]
     fn deque_new_initial_size_zero() {
         let d = Deque::<u32>::new(5);
         let size = d.size();
         
         assert_eq!(size, 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn deque_is_empty_tracks_push_and_pop() {
+        let mut d = Deque::new(5);
+        assert!(d.is_empty());
+
+        let ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+        assert!(!d.is_empty());
+
+        let ret = d.pop_front();
+        assert_eq!(ret, Some(1u32));
+        assert!(d.is_empty());
+    }
+
+    #[test]
+    fn deque_is_full_exactly_at_capacity() {
+        let mut d = Deque::new(3);
+        assert!(!d.is_full());
+
+        let mut ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(2u32);
+        assert_eq!(ret, Ok(()));
+        assert!(!d.is_full());
+
+        ret = d.push_front(3u32);
+        assert_eq!(ret, Ok(()));
+        assert!(d.is_full());
+
+        ret = d.push_back(4u32);
+        assert_eq!(ret, Err(DequeFullError));
+        assert!(d.is_full());
+
+        d.pop_front();
+        assert!(!d.is_full());
+    }
+
+    #[test]
+    fn deque_clear_resets_slots_and_preserves_capacity() {
+        let mut d = Deque::new(5);
+
+        let mut ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        d.clear();
+        assert_eq!(d.len(), 0);
+        assert_eq!(d.tail, 0);
+        assert_eq!(d.data.capacity(), 5);
+
+        for element in &d.data {
+            assert_eq!(element, &None);
+        }
+    }
+
+    #[test]
+    fn deque_clear_when_full_behaves_like_new() {
+        let mut d = Deque::new(3);
+
+        let mut ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+        assert!(d.is_full());
+
+        d.clear();
+        assert_eq!(d.len(), 0);
+        assert_eq!(d.capacity(), 3);
+        assert_eq!(d.peek_front(), None);
+        assert_eq!(d.peek_back(), None);
+
+        ret = d.push_back(4u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(5u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(6u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(d.push_back(7u32), Err(DequeFullError));
+
+        assert_eq!(d.pop_front(), Some(5u32));
+        assert_eq!(d.pop_front(), Some(4u32));
+        assert_eq!(d.pop_front(), Some(6u32));
+        assert_eq!(d.pop_front(), None);
+    }
+
+    #[test]
+    fn deque_capacity_unchanged_by_push_pop_clear() {
+        let mut d = Deque::new(5);
+        assert_eq!(d.capacity(), 5);
+
+        let mut ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(d.capacity(), 5);
+
+        d.pop_front();
+        d.pop_back();
+        assert_eq!(d.capacity(), 5);
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        d.clear();
+        assert_eq!(d.capacity(), 5);
+    }
+
+    #[test]
+    fn deque_debug_shows_logical_order_after_wraparound() {
+        let mut d = Deque::<u32>::new(5);
+
+        assert_eq!(
+            format!("{:?}", d),
+            "Deque { size: 0, capacity: 5, elements: [] }"
+        );
+
+        d.data[3] = Some(1u32);
+        d.data[4] = Some(2u32);
+        d.data[0] = Some(3u32);
+        d.tail = 1;
+        d.count = 3;
+
+        assert_eq!(
+            format!("{:?}", d),
+            "Deque { size: 3, capacity: 5, elements: [1, 2, 3] }"
+        );
+    }
+
+    #[test]
+    fn deque_clone_preserves_layout() {
+        let mut d = Deque::new(5);
+
+        let mut ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(3u32);
+        assert_eq!(ret, Ok(()));
+
+        let mut cloned = d.clone();
+        assert_eq!(cloned.data, d.data);
+        assert_eq!(cloned.data.capacity(), d.data.capacity());
+        assert_eq!(cloned.count, d.count);
+        assert_eq!(cloned.tail, d.tail);
+
+        for _ in 0..3 {
+            assert_eq!(cloned.pop_front(), d.pop_front());
+        }
+    }
+
+    #[test]
+    fn deque_eq_compares_logical_contents() {
+        let mut a = Deque::<u32>::new(5);
+        let mut b = Deque::<u32>::new(3);
+
+        assert!(a == b);
+
+        a.data[3] = Some(1u32);
+        a.data[4] = Some(2u32);
+        a.tail = 0;
+        a.count = 2;
+
+        b.data[0] = Some(1u32);
+        b.data[1] = Some(2u32);
+        b.tail = 2;
+        b.count = 2;
+
+        assert!(a == b);
+
+        b.data[1] = Some(3u32);
+        assert!(a != b);
+    }
+
+    #[test]
+    fn deque_push_back_pop_front_is_fifo() {
+        let mut d = Deque::new(5);
+
+        let mut ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.pop_front(), Some(1u32));
+        assert_eq!(d.pop_front(), Some(2u32));
+        assert_eq!(d.pop_front(), None);
+    }
+
+    #[test]
+    fn deque_push_front_pop_back_is_fifo() {
+        let mut d = Deque::new(5);
+
+        let mut ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(2u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.pop_back(), Some(1u32));
+        assert_eq!(d.pop_back(), Some(2u32));
+        assert_eq!(d.pop_back(), None);
+    }
+
+    #[test]
+    fn deque_push_back_pop_back_is_lifo() {
+        let mut d = Deque::new(5);
+
+        let mut ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.pop_back(), Some(2u32));
+        assert_eq!(d.pop_back(), Some(1u32));
+        assert_eq!(d.pop_back(), None);
+    }
+
+    #[test]
+    fn deque_push_front_pop_front_is_lifo() {
+        let mut d = Deque::new(5);
+
+        let mut ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(2u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.pop_front(), Some(2u32));
+        assert_eq!(d.pop_front(), Some(1u32));
+        assert_eq!(d.pop_front(), None);
+    }
+
+    #[test]
+    fn deque_interleaved_pushes_keep_order() {
+        let mut d = Deque::new(5);
+
+        let mut ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(4u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(5u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(0u32);
+        assert_eq!(ret, Err(DequeFullError));
+
+        assert_eq!(d.peek_front(), Some(&1u32));
+        assert_eq!(d.peek_back(), Some(&5u32));
+
+        for i in 1..=5u32 {
+            assert_eq!(d.pop_front(), Some(i));
+        }
+
+        assert_eq!(d.pop_front(), None);
+    }
+
+    #[test]
+    fn deque_peeks_track_both_ends_across_wraparound() {
+        let mut d = Deque::new(3);
+
+        for i in 0..10u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(d.peek_back(), Some(&i));
+
+            if d.is_full() {
+                assert_eq!(d.pop_front(), Some(i - 2));
+            }
+
+            assert_eq!(d.peek_front(), Some(&i.saturating_sub(1)));
+        }
+
+        for i in 0..10u32 {
+            let ret = d.push_front(i);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(d.peek_front(), Some(&i));
+
+            if d.is_full() {
+                d.pop_back();
+            }
+        }
+
+        assert_eq!(d.pop_back(), Some(8u32));
+        assert_eq!(d.pop_back(), Some(9u32));
+        assert_eq!(d.pop_back(), None);
+    }
+
+    #[test]
+    fn deque_mixed_operations_match_vecdeque() {
+        use std::collections::VecDeque;
+
+        for capacity in 1..=5usize {
+            let mut d = Deque::new(capacity);
+            let mut expected = VecDeque::new();
+
+            // simple LCG so the sequence of operations is deterministic
+            let mut seed = 0x2545_f491u32;
+
+            for i in 0..200u32 {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+
+                match (seed >> 16) % 4 {
+                    0 => {
+                        let ret = d.push_front(i);
+                        if expected.len() < capacity {
+                            expected.push_front(i);
+                            assert_eq!(ret, Ok(()));
+                        } else {
+                            assert_eq!(ret, Err(DequeFullError));
+                        }
+                    }
+                    1 => {
+                        let ret = d.push_back(i);
+                        if expected.len() < capacity {
+                            expected.push_back(i);
+                            assert_eq!(ret, Ok(()));
+                        } else {
+                            assert_eq!(ret, Err(DequeFullError));
+                        }
+                    }
+                    2 => assert_eq!(d.pop_front(), expected.pop_front()),
+                    _ => assert_eq!(d.pop_back(), expected.pop_back()),
+                }
+
+                assert_eq!(d.len(), expected.len());
+                assert_eq!(d.peek_front(), expected.front());
+                assert_eq!(d.peek_back(), expected.back());
+            }
+        }
+    }
+
+    #[test]
+    fn deque_peek_when_empty_returns_none() {
+        let d = Deque::<u32>::new(5);
+
+        assert_eq!(d.peek_front(), None);
+        assert_eq!(d.peek_back(), None);
+    }
+
+    #[test]
+    fn deque_peek_distinguishes_stored_none() {
+        let mut d = Deque::<Option<u32>>::new(5);
+
+        let mut ret = d.push_back(None);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(Some(1u32));
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.peek_front(), Some(&None));
+        assert_eq!(d.peek_back(), Some(&Some(1u32)));
+
+        assert_eq!(d.pop_back(), Some(Some(1u32)));
+        assert_eq!(d.peek_back(), Some(&None));
+
+        assert_eq!(d.pop_front(), Some(None));
+        assert_eq!(d.peek_front(), None);
+        assert_eq!(d.peek_back(), None);
+    }
+
+    #[test]
+    fn deque_display_when_empty() {
+        let d = Deque::<u32>::new(5);
+
+        assert_eq!(d.to_string(), "[]");
+    }
+
+    #[test]
+    fn deque_display_single_element() {
+        let mut d = Deque::new(5);
+
+        let ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.to_string(), "[1]");
+    }
+
+    #[test]
+    fn deque_display_front_to_back_after_wraparound() {
+        let mut d = Deque::new(3);
+
+        let mut ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.to_string(), "[1, 2, 3]");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deque_serde_round_trip_after_wraparound() {
+        let mut d = Deque::new(4);
+
+        let mut ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, r#"{"capacity":4,"elements":[1,2,3]}"#);
+
+        let mut restored: Deque<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.data.capacity(), 4);
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.pop_back(), Some(3u32));
+        assert_eq!(restored.pop_front(), Some(1u32));
+        assert_eq!(restored.pop_front(), Some(2u32));
+        assert_eq!(restored.pop_front(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deque_deserialize_more_elements_than_capacity_errors() {
+        let json = r#"{"capacity":1,"elements":[1,2]}"#;
+
+        let ret = serde_json::from_str::<Deque<u32>>(json);
+        assert!(ret.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deque_deserialize_absurd_capacity_errors() {
+        let json = r#"{"capacity":18446744073709551615,"elements":[]}"#;
+
+        let ret = serde_json::from_str::<Deque<u32>>(json);
+        assert!(ret.unwrap_err().to_string().contains("allocation failed"));
+    }
+
+    #[test]
+    fn deque_moves_strings_in_and_out_of_both_ends() {
+        let mut d = Deque::new(3);
+
+        let mut ret = d.push_back(String::from("b"));
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(String::from("a"));
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(String::from("c"));
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.peek_front().map(String::as_str), Some("a"));
+        assert_eq!(d.peek_back().map(String::as_str), Some("c"));
+
+        assert_eq!(d.pop_back(), Some(String::from("c")));
+        assert_eq!(d.pop_front(), Some(String::from("a")));
+
+        ret = d.push_front(String::from("z"));
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.len(), 2);
+        assert_eq!(d.pop_front(), Some(String::from("z")));
+        assert_eq!(d.pop_front(), Some(String::from("b")));
+        assert_eq!(d.pop_front(), None);
+
+        for element in &d.data {
+            assert_eq!(element, &None);
+        }
+    }
+
+    #[test]
+    fn deque_holds_non_clone_type() {
+        #[derive(Debug, PartialEq)]
+        struct NoClone(u32);
+
+        let mut d = Deque::new(2);
+
+        let mut ret = d.push_front(NoClone(1));
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(NoClone(2));
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(NoClone(3));
+        assert_eq!(ret, Err(DequeFullError));
+
+        assert_eq!(d.pop_back(), Some(NoClone(2)));
+
+        ret = d.push_front(NoClone(0));
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.len(), 2);
+        assert_eq!(d.pop_front(), Some(NoClone(0)));
+        assert_eq!(d.pop_back(), Some(NoClone(1)));
+        assert_eq!(d.pop_back(), None);
+    }
+
+    #[test]
+    fn deque_len_tracks_push_and_pop() {
+        let mut d = Deque::new(5);
+        assert_eq!(d.len(), 0);
+
+        let mut ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(d.len(), 1);
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(d.len(), 2);
+
+        d.pop_back();
+        assert_eq!(d.len(), 1);
+
+        d.pop_front();
+        d.pop_front();
+        assert_eq!(d.len(), 0);
+    }
+
+    #[test]
+    fn deque_contains_after_wraparound() {
+        let mut d = Deque::new(3);
+        assert!(!d.contains(&1u32));
+
+        let mut ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        d.pop_back();
+
+        assert!(d.contains(&1u32));
+        assert!(d.contains(&2u32));
+        assert!(!d.contains(&3u32));
+    }
+
+    #[test]
+    fn deque_push_back_overwrite_evicts_front() {
+        let mut d = Deque::new(3);
+
+        for i in 1..=3u32 {
+            assert_eq!(d.push_back_overwrite(i), None);
+        }
+
+        assert_eq!(d.push_back(4u32), Err(DequeFullError));
+        assert_eq!(d.push_back_overwrite(4u32), Some(1u32));
+        assert_eq!(d.push_back_overwrite(5u32), Some(2u32));
+
+        assert_eq!(d.len(), 3);
+        assert_eq!(d.peek_front(), Some(&3u32));
+        assert_eq!(d.peek_back(), Some(&5u32));
+
+        for i in 3..=5u32 {
+            assert_eq!(d.pop_front(), Some(i));
+        }
+
+        assert_eq!(d.pop_front(), None);
+    }
+
+    #[test]
+    fn deque_push_front_overwrite_evicts_back() {
+        let mut d = Deque::new(3);
+
+        for i in 1..=3u32 {
+            assert_eq!(d.push_front_overwrite(i), None);
+        }
+
+        assert_eq!(d.push_front(4u32), Err(DequeFullError));
+        assert_eq!(d.push_front_overwrite(4u32), Some(1u32));
+        assert_eq!(d.push_front_overwrite(5u32), Some(2u32));
+
+        assert_eq!(d.len(), 3);
+        assert_eq!(d.peek_front(), Some(&5u32));
+        assert_eq!(d.peek_back(), Some(&3u32));
+
+        for i in 3..=5u32 {
+            assert_eq!(d.pop_back(), Some(i));
+        }
+
+        assert_eq!(d.pop_back(), None);
+    }
+
+    #[test]
+    fn deque_overwrite_both_ends_keeps_order() {
+        let mut d = Deque::new(3);
+
+        let mut ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.push_front_overwrite(0u32), Some(3u32));
+        assert_eq!(d.push_back_overwrite(3u32), Some(0u32));
+        assert_eq!(d.push_front_overwrite(9u32), Some(3u32));
+
+        assert_eq!(d.pop_front(), Some(9u32));
+        assert_eq!(d.pop_front(), Some(1u32));
+        assert_eq!(d.pop_front(), Some(2u32));
+        assert_eq!(d.pop_front(), None);
+    }
+
+    #[test]
+    fn deque_get_matches_pop_front_order_after_wraparound() {
+        let mut d = Deque::new(5);
+
+        for i in 1..=4u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.pop_front();
+        d.pop_front();
+
+        let mut ret = d.push_back(5u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(6u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(2u32);
+        assert_eq!(ret, Ok(()));
+
+        let indexed: Vec<u32> = (0..d.len()).map(|i| *d.get(i).unwrap()).collect();
+        assert_eq!(indexed, vec![2u32, 3u32, 4u32, 5u32, 6u32]);
+        assert_eq!(d.get(5), None);
+
+        for val in indexed {
+            assert_eq!(d.pop_front(), Some(val));
+        }
+    }
+
+    #[test]
+    fn deque_get_out_of_range_returns_none() {
+        let mut d = Deque::<u32>::new(3);
+
+        assert_eq!(d.get(0), None);
+        assert_eq!(d.get_mut(0), None);
+
+        let ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.get(0), Some(&1u32));
+        assert_eq!(d.get(1), None);
+        assert_eq!(d.get(usize::MAX), None);
+    }
+
+    #[test]
+    fn deque_get_mut_modifies_in_place() {
+        let mut d = Deque::new(3);
+
+        let mut ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        if let Some(val) = d.get_mut(0) {
+            *val = 10u32;
+        }
+
+        if let Some(val) = d.get_mut(2) {
+            *val = 30u32;
+        }
+
+        assert_eq!(d.pop_front(), Some(10u32));
+        assert_eq!(d.pop_front(), Some(2u32));
+        assert_eq!(d.pop_front(), Some(30u32));
+    }
+
+    #[test]
+    fn deque_swap_across_wrap_boundary() {
+        let mut d = Deque::new(5);
+
+        for i in 1..=5u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.pop_front();
+        d.pop_front();
+        d.pop_front();
+
+        let mut ret = d.push_back(6u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(7u32);
+        assert_eq!(ret, Ok(()));
+
+        // logical [4, 5, 6, 7] stored as [6, 7, _, 4, 5]
+        d.swap(0, 3);
+        d.swap(1, 2);
+
+        for i in (4..=7u32).rev() {
+            assert_eq!(d.pop_front(), Some(i));
+        }
+    }
+
+    #[test]
+    fn deque_swap_same_index_is_noop() {
+        let mut d = Deque::new(3);
+
+        let mut ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        d.swap(1, 1);
+
+        assert_eq!(d.pop_front(), Some(1u32));
+        assert_eq!(d.pop_front(), Some(2u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn deque_swap_out_of_range_should_panic() {
+        let mut d = Deque::new(3);
+
+        let ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        d.swap(0, 1);
+    }
+
+    #[test]
+    fn deque_rotate_left_full() {
+        let mut d = Deque::new(5);
+
+        for i in 1..=5u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.rotate_left(2);
+        assert_eq!(d.to_string(), "[3, 4, 5, 1, 2]");
+
+        for i in [3u32, 4u32, 5u32, 1u32, 2u32] {
+            assert_eq!(d.pop_front(), Some(i));
+        }
+    }
+
+    #[test]
+    fn deque_rotate_right_full() {
+        let mut d = Deque::new(5);
+
+        for i in 1..=5u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.rotate_right(2);
+        assert_eq!(d.to_string(), "[4, 5, 1, 2, 3]");
+        assert_eq!(d.peek_back(), Some(&3u32));
+    }
+
+    #[test]
+    fn deque_rotate_with_empty_slots() {
+        let mut d = Deque::new(7);
+
+        for i in 1..=5u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.rotate_left(2);
+        assert_eq!(d.to_string(), "[3, 4, 5, 1, 2]");
+
+        d.rotate_right(4);
+        assert_eq!(d.to_string(), "[4, 5, 1, 2, 3]");
+
+        let ret = d.push_front(0u32);
+        assert_eq!(ret, Ok(()));
+
+        let ret = d.push_back(6u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(d.to_string(), "[0, 4, 5, 1, 2, 3, 6]");
+    }
+
+    #[test]
+    fn deque_rotate_by_count_is_noop() {
+        let mut d = Deque::new(6);
+
+        for i in 1..=5u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.rotate_left(5);
+        assert_eq!(d.to_string(), "[1, 2, 3, 4, 5]");
+
+        d.rotate_right(10);
+        assert_eq!(d.to_string(), "[1, 2, 3, 4, 5]");
+
+        d.rotate_left(7);
+        assert_eq!(d.to_string(), "[3, 4, 5, 1, 2]");
+    }
+
+    #[test]
+    fn deque_rotate_empty_is_noop() {
+        let mut d = Deque::<u32>::new(3);
+
+        d.rotate_left(2);
+        d.rotate_right(2);
+
+        assert_eq!(d.len(), 0);
+        assert_eq!(d.to_string(), "[]");
+    }
+
+    #[test]
+    fn deque_iter_matches_pop_front_after_wraparound() {
+        let mut d = Deque::new(4);
+
+        let mut ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(0u32);
+        assert_eq!(ret, Ok(()));
+
+        d.pop_back();
+
+        ret = d.push_front(9u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        let items: Vec<u32> = d.iter().copied().collect();
+        assert_eq!(items, vec![9u32, 0u32, 1u32, 3u32]);
+        assert_eq!(d.iter().count(), d.len());
+
+        for item in items {
+            assert_eq!(d.pop_front(), Some(item));
+        }
+    }
+
+    #[test]
+    fn deque_iter_when_empty_yields_nothing() {
+        let mut d = Deque::<u32>::new(3);
+        assert_eq!(d.iter().count(), 0);
+
+        let ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+        d.pop_front();
+
+        assert_eq!(d.iter().count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn deque_full_error_propagates_as_boxed_error() {
+        fn overflow() -> Result<(), Box<dyn std::error::Error>> {
+            let mut d = Deque::new(1);
+
+            d.push_back(1u32)?;
+            d.push_front(2u32)?;
+
+            Ok(())
+        }
+
+        let err = overflow().unwrap_err();
+        assert_eq!(err.to_string(), "deque is full");
+        assert!(err.downcast_ref::<DequeFullError>().is_some());
+    }
+
+    #[test]
+    fn deque_from_std_preserves_order() {
+        let vd: VecDeque<u32> = (1..=4u32).collect();
+
+        let mut d = Deque::from_std(vd, 6).unwrap();
+        assert_eq!(d.len(), 4);
+        assert_eq!(d.capacity(), 6);
+        assert_eq!(d.pop_front(), Some(1u32));
+        assert_eq!(d.pop_back(), Some(4u32));
+        assert_eq!(d.pop_front(), Some(2u32));
+        assert_eq!(d.pop_back(), Some(3u32));
+    }
+
+    #[test]
+    fn deque_from_std_errors_when_too_small() {
+        let vd: VecDeque<u32> = (1..=4u32).collect();
+
+        let ret = Deque::from_std(vd, 3);
+        assert!(matches!(ret, Err(DequeFullError)));
+
+        let vd: VecDeque<u32> = (1..=3u32).collect();
+
+        let ret = Deque::from_std(vd, 3);
+        assert!(ret.unwrap().is_full());
+    }
+
+    #[test]
+    fn deque_into_std_preserves_order_after_wraparound() {
+        let mut d = Deque::new(4);
+
+        let mut ret = d.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        let mut vd = d.into_std();
+        assert_eq!(vd, VecDeque::from(vec![1u32, 2u32, 3u32]));
+        assert_eq!(vd.front(), Some(&1u32));
+        assert_eq!(vd.back(), Some(&3u32));
+
+        vd.push_front(0u32);
+
+        let mut d = Deque::from_std(vd, 4).unwrap();
+        assert_eq!(d.pop_front(), Some(0u32));
+        assert_eq!(d.pop_back(), Some(3u32));
+    }
+
+    #[test]
+    fn deque_default_is_empty_and_full() {
+        let mut d = Deque::<u32>::default();
+
+        assert_eq!(d.len(), 0);
+        assert_eq!(d.capacity(), 0);
+        assert!(d.is_full());
+
+        assert_eq!(d.push_back(1u32), Err(DequeFullError));
+        assert_eq!(d.push_front(1u32), Err(DequeFullError));
+    }
+
+    #[test]
+    fn deque_remaining_plus_len_is_capacity_after_wraparound() {
+        let mut d = Deque::new(4);
+        assert_eq!(d.remaining(), 4);
+
+        for i in 0..12u32 {
+            let ret = if i % 2 == 0 { d.push_back(i) } else { d.push_front(i) };
+
+            if ret.is_err() {
+                assert_eq!(d.remaining(), 0);
+                d.pop_back();
+                d.pop_front();
+            }
+
+            assert_eq!(d.remaining() + d.len(), d.capacity());
+        }
+
+        d.clear();
+        assert_eq!(d.remaining(), 4);
+    }
+
+    #[test]
+    fn deque_as_slices_when_not_wrapped() {
+        let mut d = Deque::new(5);
+
+        for i in 1..=3u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let (front, back) = d.as_slices();
+        assert_eq!(front, &[Some(1u32), Some(2u32), Some(3u32)]);
+        assert!(back.is_empty());
+    }
+
+    #[test]
+    fn deque_as_slices_when_wrapped() {
+        let mut d = Deque::new(5);
+
+        for i in 1..=3u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut ret = d.push_front(0u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_back(4u32);
+        assert_eq!(ret, Ok(()));
+
+        // logical [0, 1, 2, 3, 4] stored as [1, 2, 3, 4, 0]
+        let (front, back) = d.as_slices();
+        assert_eq!(front, &[Some(0u32)]);
+        assert_eq!(back, &[Some(1u32), Some(2u32), Some(3u32), Some(4u32)]);
+
+        let joined: Vec<u32> = front.iter().chain(back).flatten().copied().collect();
+        assert_eq!(joined, d.iter().copied().collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn deque_as_slices_when_empty() {
+        let d = Deque::<u32>::new(3);
+        let (front, back) = d.as_slices();
+
+        assert!(front.is_empty());
+        assert!(back.is_empty());
+
+        let d = Deque::<u32>::default();
+        assert_eq!(d.as_slices(), (&[][..], &[][..]));
+    }
+
+    #[test]
+    fn deque_zero_capacity_never_panics() {
+        let mut d = Deque::<u32>::new(0);
+
+        assert_eq!(d.push_front(1u32), Err(DequeFullError));
+        assert_eq!(d.push_back(1u32), Err(DequeFullError));
+        assert_eq!(d.push_front_overwrite(1u32), Some(1u32));
+        assert_eq!(d.push_back_overwrite(1u32), Some(1u32));
+
+        assert_eq!(d.pop_front(), None);
+        assert_eq!(d.pop_back(), None);
+        assert_eq!(d.peek_front(), None);
+        assert_eq!(d.peek_back(), None);
+        assert_eq!(d.get(0), None);
+        assert_eq!(d.get_mut(0), None);
+
+        d.rotate_left(1);
+        d.rotate_right(1);
+        d.clear();
+
+        assert_eq!(d.len(), 0);
+        assert_eq!(d.remaining(), 0);
+        assert!(d.is_empty());
+        assert!(d.is_full());
+        assert!(!d.contains(&1u32));
+        assert_eq!(d.iter().next(), None);
+        assert_eq!(format!("{:?}", d), "Deque { size: 0, capacity: 0, elements: [] }");
+        assert!(d.into_std().is_empty());
+    }
+
+    #[test]
+    fn deque_equal_deques_hash_equally() {
+        use std::collections::HashSet;
+
+        let mut a = Deque::new(3);
+        let mut b = Deque::new(4);
+
+        let mut ret = a.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = a.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = b.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = b.push_back(2u32);
+        assert_eq!(ret, Ok(()));
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        set.insert(b);
+        assert_eq!(set.len(), 1);
+
+        set.insert(Deque::new(3));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn deque_linearize_when_wrapped() {
+        let mut d = Deque::new(5);
+
+        for i in 1..=3u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut ret = d.push_front(0u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(9u32);
+        assert_eq!(ret, Ok(()));
+
+        d.pop_back();
+
+        // logical [9, 0, 1, 2] stored as [1, 2, _, 9, 0]
+        d.linearize();
+        assert_eq!(&d.data[..4], &[Some(9u32), Some(0u32), Some(1u32), Some(2u32)]);
+
+        let (front, back) = d.as_slices();
+        assert_eq!(front.len(), 4);
+        assert!(back.is_empty());
+
+        assert_eq!(d.pop_front(), Some(9u32));
+        assert_eq!(d.pop_back(), Some(2u32));
+
+        ret = d.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = d.push_front(7u32);
+        assert_eq!(ret, Ok(()));
+
+        for i in [7u32, 0u32, 1u32, 3u32] {
+            assert_eq!(d.pop_front(), Some(i));
+        }
+
+        assert_eq!(d.pop_front(), None);
+    }
+
+    #[test]
+    fn deque_linearize_when_full_and_empty() {
+        let mut d = Deque::new(3);
+
+        for i in 1..=3u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.pop_front();
+
+        let ret = d.push_back(4u32);
+        assert_eq!(ret, Ok(()));
+
+        d.linearize();
+        assert_eq!(d.data, vec![Some(2u32), Some(3u32), Some(4u32)]);
+        assert_eq!(d.tail, 0);
+        assert_eq!(d.push_back(5u32), Err(DequeFullError));
+
+        let mut empty = Deque::<u32>::new(0);
+        empty.linearize();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn deque_iter_mut_updates_in_place_when_wrapped() {
+        let mut d = Deque::new(5);
+
+        for i in 1..=3u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let ret = d.push_front(0u32);
+        assert_eq!(ret, Ok(()));
+
+        // logical [0, 1, 2, 3] stored as [1, 2, 3, _, 0]
+        let mut order = Vec::new();
+
+        for val in d.iter_mut() {
+            order.push(*val);
+            *val += 10;
+        }
+
+        assert_eq!(order, vec![0u32, 1u32, 2u32, 3u32]);
+
+        for i in 10..=13u32 {
+            assert_eq!(d.pop_front(), Some(i));
+        }
+
+        assert_eq!(d.pop_front(), None);
+    }
+
+    #[test]
+    fn deque_iter_mut_when_empty() {
+        let mut d = Deque::<u32>::new(3);
+        assert_eq!(d.iter_mut().next(), None);
+
+        let mut d = Deque::<u32>::new(0);
+        assert_eq!(d.iter_mut().next(), None);
+    }
+
+    #[test]
+    fn deque_cycle_iter_repeats_front_to_back() {
+        let mut d = Deque::new(4);
+
+        for i in 1..=3u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let ret = d.push_front(0u32);
+        assert_eq!(ret, Ok(()));
+
+        let items: Vec<&u32> = d.cycle_iter().take(2 * d.len()).collect();
+        assert_eq!(items, vec![&0, &1, &2, &3, &0, &1, &2, &3]);
+    }
+
+    #[test]
+    fn deque_cycle_iter_when_empty_is_exhausted() {
+        let d = Deque::<u32>::new(3);
+        assert_eq!(d.cycle_iter().next(), None);
+
+        let d = Deque::<u32>::new(0);
+        assert_eq!(d.cycle_iter().next(), None);
+    }
+
+    #[test]
+    fn deque_retain_when_wrapped() {
+        let mut d = Deque::new(6);
+
+        for i in 1..=4u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for i in [0u32, 9u32] {
+            let ret = d.push_front(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // logical [9, 0, 1, 2, 3, 4] stored as [1, 2, 3, 4, 9, 0]
+        d.retain(|x| x % 2 == 0);
+
+        assert_eq!(d.len(), 3);
+        assert_eq!(d.peek_front(), Some(&0u32));
+        assert_eq!(d.peek_back(), Some(&4u32));
+        assert_eq!(d.iter().collect::<Vec<&u32>>(), vec![&0u32, &2u32, &4u32]);
+
+        let empty = d.data.iter().filter(|slot| slot.is_none()).count();
+        assert_eq!(empty, 3);
+
+        for i in [5u32, 7u32, 11u32] {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(d.push_front(13u32), Err(DequeFullError));
+        assert_eq!(d.pop_back(), Some(11u32));
+        assert_eq!(d.pop_front(), Some(0u32));
+    }
+
+    #[test]
+    fn deque_retain_all_none_and_empty() {
+        let mut d = Deque::new(3);
+        d.retain(|_: &u32| false);
+        assert!(d.is_empty());
+
+        for i in 1..=3u32 {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.retain(|_| true);
+        assert_eq!(d.len(), 3);
+        assert!(d.is_full());
+
+        d.retain(|_| false);
+        assert!(d.is_empty());
+        assert_eq!(d.peek_front(), None);
+        assert_eq!(d.peek_back(), None);
+    }
+
+    #[test]
+    fn deque_ord_is_lexicographic() {
+        let mut a = Deque::new(3);
+        let mut b = Deque::new(5);
+
+        for i in [2u32, 3u32] {
+            let ret = a.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let ret = a.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+
+        for i in [1u32, 2u32] {
+            let ret = b.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // [1, 2, 3] against [1, 2]
+        assert!(a > b);
+
+        let ret = b.push_back(4u32);
+        assert_eq!(ret, Ok(()));
+
+        // [1, 2, 3] against [1, 2, 4]
+        assert_eq!(a.cmp(&b), Ordering::Less);
+
+        b.pop_back();
+        let ret = b.push_back(3u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+    }
+
+    #[test]
+    fn deque_binary_search_when_wrapped() {
+        let mut d = Deque::new(6);
+
+        for i in [30u32, 40u32, 50u32] {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for i in [20u32, 10u32] {
+            let ret = d.push_front(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // logical [10, 20, 30, 40, 50] stored as [30, 40, 50, _, 10, 20]
+        for (i, val) in [10u32, 20u32, 30u32, 40u32, 50u32].iter().enumerate() {
+            assert_eq!(d.binary_search(val), Ok(i));
+        }
+
+        assert_eq!(d.binary_search(&5u32), Err(0));
+        assert_eq!(d.binary_search(&25u32), Err(2));
+        assert_eq!(d.binary_search(&45u32), Err(4));
+        assert_eq!(d.binary_search(&55u32), Err(5));
+    }
+
+    #[test]
+    fn deque_binary_search_when_empty() {
+        let d = Deque::<u32>::new(3);
+        assert_eq!(d.binary_search(&1u32), Err(0));
+
+        let d = Deque::<u32>::new(0);
+        assert_eq!(d.binary_search(&1u32), Err(0));
+    }
+
+    #[test]
+    fn deque_iter_next_and_next_back_meet_in_middle() {
+        let mut d = Deque::new(6);
+
+        for i in [3u32, 4u32, 5u32] {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for i in [2u32, 1u32] {
+            let ret = d.push_front(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // logical [1, 2, 3, 4, 5] stored as [3, 4, 5, _, 1, 2]
+        let mut iter = d.iter();
+
+        assert_eq!(iter.next(), Some(&1u32));
+        assert_eq!(iter.next_back(), Some(&5u32));
+        assert_eq!(iter.next(), Some(&2u32));
+        assert_eq!(iter.next_back(), Some(&4u32));
+        assert_eq!(iter.next(), Some(&3u32));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn deque_iter_rev_yields_count_elements() {
+        let mut d = Deque::new(4);
+
+        for i in [1u32, 2u32, 3u32, 4u32] {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        d.pop_front();
+        d.pop_front();
+
+        for i in [5u32, 6u32] {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(d.iter().count(), d.len());
+        assert_eq!(d.iter().rev().copied().collect::<Vec<u32>>(), vec![6u32, 5u32, 4u32, 3u32]);
+
+        let d = Deque::<u32>::new(0);
+        assert_eq!(d.iter().next_back(), None);
+    }
+
+    #[test]
+    fn deque_try_new_with_absurd_size_is_err() {
+        // the size overflows before any allocation is attempted
+        let ret = Deque::<u32>::try_new(isize::MAX as usize);
+        assert_eq!(ret.err(), Some(AllocError));
+
+        let ret = Deque::<u64>::try_new(usize::MAX);
+        assert_eq!(ret.err(), Some(AllocError));
+    }
+
+    #[test]
+    fn deque_try_new_creates_empty_deque() {
+        let mut d = Deque::<u32>::try_new(3).unwrap();
+
+        assert!(d.is_empty());
+        assert_eq!(d.data.len(), 3);
+        assert!(d.data.iter().all(Option::is_none));
+
+        for i in [2u32, 3u32] {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let ret = d.push_front(1u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(d.push_back(4u32), Err(DequeFullError));
+
+        assert_eq!(d.pop_front(), Some(1u32));
+        assert_eq!(d.pop_back(), Some(3u32));
+    }
+
+    #[test]
+    fn deque_reserve_when_wrapped_keeps_order() {
+        let mut d = Deque::new(4);
+
+        for i in [3u32, 4u32] {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for i in [2u32, 1u32] {
+            let ret = d.push_front(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // logical [1, 2, 3, 4] stored as [3, 4, 1, 2]
+        assert!(d.is_full());
+
+        d.reserve(2);
+
+        assert_eq!(d.capacity(), 6);
+        assert_eq!(d.tail, 4);
+        assert_eq!(d.data, vec![Some(1u32), Some(2u32), Some(3u32), Some(4u32), None, None]);
+
+        let ret = d.push_back(5u32);
+        assert_eq!(ret, Ok(()));
+        let ret = d.push_front(0u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(d.push_back(6u32), Err(DequeFullError));
+
+        assert_eq!(d.pop_front(), Some(0u32));
+        assert_eq!(d.pop_back(), Some(5u32));
+        assert_eq!(d.pop_back(), Some(4u32));
+        assert_eq!(d.pop_front(), Some(1u32));
+        assert_eq!(d.pop_front(), Some(2u32));
+        assert_eq!(d.pop_back(), Some(3u32));
+        assert_eq!(d.pop_back(), None);
+    }
+
+    #[test]
+    fn deque_reserve_with_enough_room_is_noop() {
+        let mut d = Deque::new(4);
+
+        let ret = d.push_back(1u32);
+        assert_eq!(ret, Ok(()));
+
+        d.reserve(3);
+        assert_eq!(d.capacity(), 4);
+
+        let mut d = Deque::<u32>::new(0);
+        d.reserve(2);
+
+        assert_eq!(d.capacity(), 2);
+        assert_eq!(d.push_front(1u32), Ok(()));
+        assert_eq!(d.push_front(2u32), Ok(()));
+        assert!(d.is_full());
+        assert_eq!(d.pop_back(), Some(1u32));
+    }
+
+    #[test]
+    fn deque_binary_search_unsorted_does_not_panic() {
+        let mut d = Deque::new(4);
+
+        for i in [40u32, 10u32, 30u32] {
+            let ret = d.push_back(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let ret = d.push_front(20u32);
+        assert_eq!(ret, Ok(()));
+
+        for target in [5u32, 10u32, 25u32, 50u32] {
+            let _ = d.binary_search(&target);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::fmt;
+
+use crate::deque::DequeFullError;
+use crate::priority::PriorityQueueFullError;
+use crate::queue::QueueFullError;
+use crate::stack::{StackAllocError, StackFullError};
+
+/// `Error` type indicating the memory for a collection could not be 
+/// allocated, returned by the fallible `try_new` constructors.
+#[derive(Debug, PartialEq)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "allocation failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AllocError {}
+
+/// `Error` type covering the errors returned by every collection in this 
+/// crate. Each collection specific error converts into a 
+/// `CollectionError`, so `?` can be used to unify them.
+/// ```
+/// use rsds::error::CollectionError;
+/// use rsds::queue::Queue;
+/// use rsds::stack::Stack;
+/// 
+/// fn move_top(s: &mut Stack<u32>, q: &mut Queue<u32>) -> Result<(), CollectionError> {
+///     if let Some(val) = s.pop() {
+///         q.enqueue(val)?;
+///         s.push(val)?;
+///     }
+/// 
+///     Ok(())
+/// }
+/// 
+/// let mut s = Stack::<u32>::new(1);
+/// let mut q = Queue::<u32>::new(0);
+/// 
+/// s.push(1u32);
+/// assert_eq!(move_top(&mut s, &mut q), Err(CollectionError::Full));
+/// ```
+#[derive(Debug, PartialEq)]
+pub enum CollectionError {
+    /// The collection is at capacity and can not hold another element.
+    Full,
+    /// The memory for the collection could not be allocated.
+    Alloc,
+}
+
+impl fmt::Display for CollectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectionError::Full => write!(f, "collection is full"),
+            CollectionError::Alloc => write!(f, "collection allocation failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CollectionError {}
+
+impl From<StackFullError> for CollectionError {
+    fn from(_: StackFullError) -> Self {
+        CollectionError::Full
+    }
+}
+
+impl From<QueueFullError> for CollectionError {
+    fn from(_: QueueFullError) -> Self {
+        CollectionError::Full
+    }
+}
+
+impl From<DequeFullError> for CollectionError {
+    fn from(_: DequeFullError) -> Self {
+        CollectionError::Full
+    }
+}
+
+impl From<PriorityQueueFullError> for CollectionError {
+    fn from(_: PriorityQueueFullError) -> Self {
+        CollectionError::Full
+    }
+}
+
+impl From<AllocError> for CollectionError {
+    fn from(_: AllocError) -> Self {
+        CollectionError::Alloc
+    }
+}
+
+impl From<StackAllocError> for CollectionError {
+    fn from(_: StackAllocError) -> Self {
+        CollectionError::Alloc
+    }
+}
+
+#[cfg(test)]
+mod test_error {
+    use crate::error::*;
+    use crate::deque::Deque;
+    use crate::priority::PriorityQueue;
+    use crate::queue::Queue;
+    use crate::stack::Stack;
+
+    #[test]
+    fn error_converts_from_each_full_error() {
+        assert_eq!(CollectionError::from(StackFullError), CollectionError::Full);
+        assert_eq!(CollectionError::from(QueueFullError), CollectionError::Full);
+        assert_eq!(CollectionError::from(DequeFullError), CollectionError::Full);
+        assert_eq!(CollectionError::from(PriorityQueueFullError), CollectionError::Full);
+    }
+
+    #[test]
+    fn error_question_mark_unifies_full_errors() {
+        fn fill(size: usize) -> Result<(), CollectionError> {
+            let mut stack = Stack::new(size);
+            let mut queue = Queue::new(size);
+            let mut d = Deque::new(size);
+            let mut pq = PriorityQueue::new(size);
+
+            for i in 0..=size as u32 {
+                stack.push(i)?;
+                queue.enqueue(i)?;
+                d.push_back(i)?;
+                pq.push(i)?;
+            }
+
+            Ok(())
+        }
+
+        assert_eq!(fill(3), Err(CollectionError::Full));
+    }
+
+    #[test]
+    fn error_converts_from_each_alloc_error() {
+        assert_eq!(CollectionError::from(AllocError), CollectionError::Alloc);
+        assert_eq!(CollectionError::from(StackAllocError), CollectionError::Alloc);
+    }
+
+    #[test]
+    fn error_question_mark_unifies_alloc_errors() {
+        fn allocate(size: usize) -> Result<(), CollectionError> {
+            Stack::<u64>::try_new(size)?;
+            Queue::<u64>::try_new(size)?;
+            Deque::<u64>::try_new(size)?;
+
+            Ok(())
+        }
+
+        assert_eq!(allocate(3), Ok(()));
+        assert_eq!(allocate(usize::MAX), Err(CollectionError::Alloc));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn error_as_trait_object() {
+        let err: Box<dyn Error> = Box::new(CollectionError::from(StackFullError));
+
+        assert_eq!(err.to_string(), "collection is full");
+        assert!(err.source().is_none());
+    }
+}
@@ -1,3 +1,17 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 pub mod stack;
 pub mod queue;
-pub mod deque;
\ No newline at end of file
+pub mod deque;
+pub mod priority;
+pub mod collection;
+pub mod error;
+pub mod minmax;
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "std")]
+pub mod sync;
+#[cfg(feature = "std")]
+pub mod rate;
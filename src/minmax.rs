@@ -0,0 +1,263 @@
+
+use alloc::vec::Vec;
+use crate::stack::{Stack, StackFullError};
+
+/// A heap allocated stack that holds elements of type `T` and tracks the 
+/// minimum and maximum element it holds. Both can be queried in constant 
+/// time. Otherwise behaves exactly like a `Stack`, including returning a 
+/// `StackFullError` when full.
+pub struct MinMaxStack<T: Ord + Clone> {
+    stack: Stack<T>,
+    // the min and max of the elements at or below each position
+    mins: Vec<T>,
+    maxes: Vec<T>,
+}
+
+impl<T: Ord + Clone> MinMaxStack<T> {
+    /// Create a new `MinMaxStack` with a max capacity of `size`.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// 
+    /// // Initialize an empty stack with a max capacity of 5 `u32`'s.
+    /// let s = MinMaxStack::<u32>::new(5);
+    /// ```
+    pub fn new(size: usize) -> Self {
+        MinMaxStack {
+            stack: Stack::new(size),
+            mins: Vec::with_capacity(size),
+            maxes: Vec::with_capacity(size),
+        }
+    }
+
+    /// Push a value onto the stack if the stack is not full. If the 
+    /// stack is full, a `StackFullError` is returned.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// use rsds::stack::StackFullError;
+    /// 
+    /// let mut s = MinMaxStack::<u32>::new(1);
+    /// 
+    /// assert_eq!(s.push(1u32), Ok(()));
+    /// assert_eq!(s.push(2u32), Err(StackFullError));
+    /// ```
+    pub fn push(&mut self, val: T) -> Result<(), StackFullError> {
+        let min = match self.mins.last() {
+            Some(min) if *min < val => min.clone(),
+            _ => val.clone(),
+        };
+
+        let max = match self.maxes.last() {
+            Some(max) if *max > val => max.clone(),
+            _ => val.clone(),
+        };
+
+        self.stack.push(val)?;
+        self.mins.push(min);
+        self.maxes.push(max);
+
+        Ok(())
+    }
+
+    /// Removes an element from the stack if one exists. 
+    /// Returns `Some(T)` or `None` if the stack is empty.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// 
+    /// let mut s = MinMaxStack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// 
+    /// assert_eq!(s.pop(), Some(1u32));
+    /// assert_eq!(s.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        self.mins.pop();
+        self.maxes.pop();
+        self.stack.pop()
+    }
+
+    /// Returns a reference to the element on top of the stack without 
+    /// removing it, or `None` if the stack is empty.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// 
+    /// let mut s = MinMaxStack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// 
+    /// assert_eq!(s.peek(), Some(&1u32));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.stack.peek()
+    }
+
+    /// Returns a reference to the smallest element in the stack, or 
+    /// `None` if the stack is empty.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// 
+    /// let mut s = MinMaxStack::<u32>::new(5);
+    /// 
+    /// s.push(2u32);
+    /// s.push(1u32);
+    /// s.push(3u32);
+    /// 
+    /// assert_eq!(s.min(), Some(&1u32));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        self.mins.last()
+    }
+
+    /// Returns a reference to the largest element in the stack, or 
+    /// `None` if the stack is empty.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// 
+    /// let mut s = MinMaxStack::<u32>::new(5);
+    /// 
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// s.push(1u32);
+    /// 
+    /// assert_eq!(s.max(), Some(&3u32));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        self.maxes.last()
+    }
+
+    /// Returns the number of elements in the stack as a `usize`.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// 
+    /// let mut s = MinMaxStack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// 
+    /// assert_eq!(s.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns `true` if the stack contains no elements.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// 
+    /// let s = MinMaxStack::<u32>::new(5);
+    /// 
+    /// assert!(s.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Returns the max capacity of the stack as a `usize`.
+    /// ```
+    /// use rsds::minmax::MinMaxStack;
+    /// 
+    /// let s = MinMaxStack::<u32>::new(5);
+    /// 
+    /// assert_eq!(s.capacity(), 5);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.stack.capacity()
+    }
+}
+
+#[cfg(test)]
+mod test_minmax {
+    use crate::minmax::*;
+
+    #[test]
+    fn minmax_empty_returns_none() {
+        let stack = MinMaxStack::<u32>::new(5);
+
+        assert_eq!(stack.min(), None);
+        assert_eq!(stack.max(), None);
+    }
+
+    #[test]
+    fn minmax_tracks_mixed_sequence() {
+        let mut stack = MinMaxStack::new(10);
+
+        let expected = [
+            (5u32, 5u32, 5u32),
+            (3u32, 3u32, 5u32),
+            (8u32, 3u32, 8u32),
+            (1u32, 1u32, 8u32),
+            (9u32, 1u32, 9u32),
+            (4u32, 1u32, 9u32),
+        ];
+
+        for (val, min, max) in expected {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(stack.min(), Some(&min));
+            assert_eq!(stack.max(), Some(&max));
+        }
+
+        for (val, min, max) in expected.iter().rev() {
+            assert_eq!(stack.min(), Some(min));
+            assert_eq!(stack.max(), Some(max));
+            assert_eq!(stack.pop(), Some(*val));
+        }
+
+        assert_eq!(stack.min(), None);
+        assert_eq!(stack.max(), None);
+    }
+
+    #[test]
+    fn minmax_updates_after_extremum_popped() {
+        let mut stack = MinMaxStack::new(5);
+
+        for val in [4u32, 2u32, 7u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.max(), Some(&7u32));
+        assert_eq!(stack.pop(), Some(7u32));
+        assert_eq!(stack.max(), Some(&4u32));
+
+        assert_eq!(stack.min(), Some(&2u32));
+        assert_eq!(stack.pop(), Some(2u32));
+        assert_eq!(stack.min(), Some(&4u32));
+    }
+
+    #[test]
+    fn minmax_handles_duplicate_extremes() {
+        let mut stack = MinMaxStack::new(5);
+
+        for val in [2u32, 2u32, 1u32, 1u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        stack.pop();
+        assert_eq!(stack.min(), Some(&1u32));
+
+        stack.pop();
+        assert_eq!(stack.min(), Some(&2u32));
+        assert_eq!(stack.max(), Some(&2u32));
+    }
+
+    #[test]
+    fn minmax_push_when_full_returns_stackfullerror() {
+        let mut stack = MinMaxStack::new(2);
+
+        let mut ret = stack.push(5u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(6u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(0u32);
+        assert_eq!(ret, Err(StackFullError));
+
+        // the rejected value must not affect the tracked extremes
+        assert_eq!(stack.min(), Some(&5u32));
+        assert_eq!(stack.max(), Some(&6u32));
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.mins.len(), 2);
+    }
+}
@@ -0,0 +1,306 @@
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::fmt;
+use alloc::vec::Vec;
+
+/// `Error` type indicating the `PriorityQueue` is full.
+#[derive(Debug, PartialEq)]
+pub struct PriorityQueueFullError;
+
+impl fmt::Display for PriorityQueueFullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "priority queue is full")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PriorityQueueFullError {}
+
+/// A heap allocated `PriorityQueue` of type `T`, stored as a binary 
+/// max-heap. The greatest element according to `Ord` is always removed 
+/// first.
+pub struct PriorityQueue<T: Ord> {
+    heap: Vec<T>,
+}
+
+impl<T: Ord> PriorityQueue<T> {
+    /// Create a new `PriorityQueue` with a max capacity of `size`.
+    /// ```
+    /// use rsds::priority::PriorityQueue;
+    /// 
+    /// // Empty PriorityQueue capable of holding up to 5 u32 elements.
+    /// let pq = PriorityQueue::<u32>::new(5);
+    /// ```
+    pub fn new(size: usize) -> Self {
+        PriorityQueue {
+            heap: Vec::with_capacity(size),
+        }
+    }
+
+    /// Places a value into the `PriorityQueue` if there is room or 
+    /// returns a `PriorityQueueFullError` if full.
+    /// ```
+    /// use rsds::priority::{PriorityQueue, PriorityQueueFullError};
+    /// 
+    /// let mut pq = PriorityQueue::<u32>::new(1);
+    /// 
+    /// assert_eq!(pq.push(42u32), Ok(()));
+    /// assert_eq!(pq.push(43u32), Err(PriorityQueueFullError));
+    /// ```
+    pub fn push(&mut self, val: T) -> Result<(), PriorityQueueFullError> {
+        if self.heap.len() < self.heap.capacity() {
+            self.heap.push(val);
+            self.sift_up(self.heap.len() - 1);
+            Ok(())
+        } else {
+            Err(PriorityQueueFullError)
+        }
+    }
+
+    /// Removes the greatest value from the `PriorityQueue` as an 
+    /// `Option<T>` or `None` if the `PriorityQueue` is empty.
+    /// ```
+    /// use rsds::priority::PriorityQueue;
+    /// 
+    /// let mut pq = PriorityQueue::<u32>::new(5);
+    /// 
+    /// pq.push(1u32);
+    /// pq.push(3u32);
+    /// pq.push(2u32);
+    /// 
+    /// assert_eq!(pq.pop(), Some(3u32));
+    /// assert_eq!(pq.pop(), Some(2u32));
+    /// assert_eq!(pq.pop(), Some(1u32));
+    /// assert_eq!(pq.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let last = self.heap.len() - 1;
+        self.heap.swap(0, last);
+
+        let ret = self.heap.pop();
+        self.sift_down(0);
+
+        ret
+    }
+
+    /// Returns a reference to the greatest value in the `PriorityQueue` 
+    /// without removing it, or `None` if the `PriorityQueue` is empty.
+    /// ```
+    /// use rsds::priority::PriorityQueue;
+    /// 
+    /// let mut pq = PriorityQueue::<u32>::new(5);
+    /// 
+    /// pq.push(1u32);
+    /// pq.push(3u32);
+    /// 
+    /// assert_eq!(pq.peek(), Some(&3u32));
+    /// assert_eq!(pq.len(), 2);
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.first()
+    }
+
+    /// Returns the number of elements in the `PriorityQueue` as a `usize`.
+    /// ```
+    /// use rsds::priority::PriorityQueue;
+    /// 
+    /// let mut pq = PriorityQueue::<u32>::new(5);
+    /// 
+    /// pq.push(1u32);
+    /// 
+    /// assert_eq!(pq.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if the `PriorityQueue` contains no elements.
+    /// ```
+    /// use rsds::priority::PriorityQueue;
+    /// 
+    /// let pq = PriorityQueue::<u32>::new(5);
+    /// 
+    /// assert!(pq.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns `true` if the `PriorityQueue` is at capacity, meaning the 
+    /// next `push` will return a `PriorityQueueFullError`.
+    /// ```
+    /// use rsds::priority::PriorityQueue;
+    /// 
+    /// let mut pq = PriorityQueue::<u32>::new(1);
+    /// 
+    /// pq.push(1u32);
+    /// 
+    /// assert!(pq.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.heap.len() == self.heap.capacity()
+    }
+
+    /// Returns the max capacity of the `PriorityQueue` as a `usize`.
+    /// ```
+    /// use rsds::priority::PriorityQueue;
+    /// 
+    /// let pq = PriorityQueue::<u32>::new(5);
+    /// 
+    /// assert_eq!(pq.capacity(), 5);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    /// Moves the element at `i` up the heap until its parent is no 
+    /// smaller than it.
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+
+            if self.heap[i] <= self.heap[parent] {
+                break;
+            }
+
+            self.heap.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    /// Moves the element at `i` down the heap until neither of its 
+    /// children are greater than it.
+    fn sift_down(&mut self, mut i: usize) {
+        let len = self.heap.len();
+
+        loop {
+            let left = 2 * i + 1;
+            let right = left + 1;
+            let mut largest = i;
+
+            if left < len && self.heap[left] > self.heap[largest] {
+                largest = left;
+            }
+
+            if right < len && self.heap[right] > self.heap[largest] {
+                largest = right;
+            }
+
+            if largest == i {
+                break;
+            }
+
+            self.heap.swap(i, largest);
+            i = largest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_priority {
+    use crate::priority::*;
+
+    #[test]
+    fn priority_new_creates_correct_capacity() {
+        let pq = PriorityQueue::<u32>::new(5);
+
+        assert_eq!(pq.heap.capacity(), 5);
+        assert_eq!(pq.heap.len(), 0);
+    }
+
+    #[test]
+    fn priority_pop_returns_highest_first() {
+        let mut pq = PriorityQueue::new(10);
+
+        for val in [5u32, 1u32, 9u32, 3u32, 7u32, 2u32, 8u32, 4u32, 6u32, 0u32] {
+            let ret = pq.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for expected in (0..10u32).rev() {
+            assert_eq!(pq.pop(), Some(expected));
+        }
+
+        assert_eq!(pq.pop(), None);
+    }
+
+    #[test]
+    fn priority_keeps_heap_property_with_duplicates() {
+        let mut pq = PriorityQueue::new(6);
+
+        for val in [3u32, 3u32, 1u32, 4u32, 1u32, 4u32] {
+            let ret = pq.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for i in 1..pq.heap.len() {
+            assert!(pq.heap[(i - 1) / 2] >= pq.heap[i]);
+        }
+
+        let mut popped = Vec::new();
+        while let Some(val) = pq.pop() {
+            popped.push(val);
+        }
+
+        assert_eq!(popped, vec![4u32, 4u32, 3u32, 3u32, 1u32, 1u32]);
+    }
+
+    #[test]
+    fn priority_push_when_full_returns_priorityqueuefullerror() {
+        let mut pq = PriorityQueue::new(2);
+
+        let mut ret = pq.push(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = pq.push(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = pq.push(3u32);
+        assert_eq!(ret, Err(PriorityQueueFullError));
+
+        assert_eq!(pq.pop(), Some(2u32));
+
+        ret = pq.push(3u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(pq.peek(), Some(&3u32));
+    }
+
+    #[test]
+    fn priority_peek_does_not_remove_max() {
+        let mut pq = PriorityQueue::new(5);
+        assert_eq!(pq.peek(), None);
+
+        let mut ret = pq.push(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = pq.push(7u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(pq.peek(), Some(&7u32));
+        assert_eq!(pq.peek(), Some(&7u32));
+        assert_eq!(pq.len(), 2);
+        assert_eq!(pq.pop(), Some(7u32));
+        assert_eq!(pq.peek(), Some(&2u32));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn priority_full_error_propagates_as_boxed_error() {
+        fn overflow() -> Result<(), Box<dyn std::error::Error>> {
+            let mut pq = PriorityQueue::new(1);
+
+            pq.push(1u32)?;
+            pq.push(2u32)?;
+
+            Ok(())
+        }
+
+        let err = overflow().unwrap_err();
+        assert_eq!(err.to_string(), "priority queue is full");
+        assert!(err.downcast_ref::<PriorityQueueFullError>().is_some());
+    }
+}
@@ -1,6 +1,14 @@
 
-use std::vec::Vec;
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
+use crate::collection::Collection;
+use crate::error::AllocError;
 
 /// `Error` type indicating the `Queue` is full.
 #[derive(Debug, PartialEq)]
@@ -12,16 +20,21 @@ impl fmt::Display for QueueFullError {
     }
 }
 
-/// A heap allocated `Queue` of type `T`. The type must 
-/// implement the `Clone` trait.
-pub struct Queue<T: Clone> {
+#[cfg(feature = "std")]
+impl Error for QueueFullError {}
+
+/// A heap allocated `Queue` of type `T`.
+pub struct Queue<T> {
     queue: Vec<Option<T>>,
     count: usize,
+    head: usize,
     tail: usize,
+    growable: bool,
+    on_full: Option<Box<dyn FnMut() + Send + Sync>>,
 }
 
 
-impl<T: Clone> Queue<T> {
+impl<T> Queue<T> {
     /// Create a new `Queue` with a max capacity of `size`. 
     /// ```
     /// use rsds::queue::Queue;
@@ -33,7 +46,10 @@ impl<T: Clone> Queue<T> {
         let mut queue = Queue {
             queue: Vec::<Option<T>>::with_capacity(size),
             count: 0,
+            head: 0,
             tail: 0, 
+            growable: false,
+            on_full: None,
         };
 
         for _ in 0..queue.queue.capacity() {
@@ -43,8 +59,65 @@ impl<T: Clone> Queue<T> {
         queue
     }
 
+    /// Create a new `Queue` with a max capacity of `size`, returning a 
+    /// `AllocError` instead of aborting if the memory can not be 
+    /// allocated.
+    /// ```
+    /// use rsds::error::AllocError;
+    /// use rsds::queue::Queue;
+    /// 
+    /// let q = Queue::<u32>::try_new(5).unwrap();
+    /// assert_eq!(q.capacity(), 5);
+    /// 
+    /// assert_eq!(Queue::<u32>::try_new(usize::MAX).unwrap_err(), AllocError);
+    /// ```
+    pub fn try_new(size: usize) -> Result<Self, AllocError> {
+        let mut queue = Vec::<Option<T>>::new();
+        queue.try_reserve_exact(size).map_err(|_| AllocError)?;
+
+        for _ in 0..queue.capacity() {
+            queue.push(None);
+        }
+
+        Ok(Queue {
+            queue,
+            count: 0,
+            head: 0,
+            tail: 0,
+            growable: false,
+            on_full: None,
+        })
+    }
+
+    /// Create a new unbounded `Queue`. Rather than returning a 
+    /// `QueueFullError`, an unbounded `Queue` reallocates to a larger 
+    /// capacity when a value is enqueued onto a full `Queue`.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::unbounded();
+    /// 
+    /// for i in 0..100 {
+    ///     assert_eq!(q.enqueue(i), Ok(()));
+    /// }
+    /// 
+    /// assert_eq!(q.len(), 100);
+    /// assert_eq!(q.dequeue(), Some(0u32));
+    /// ```
+    pub fn unbounded() -> Self {
+        Queue {
+            queue: Vec::new(),
+            count: 0,
+            head: 0,
+            tail: 0,
+            growable: true,
+            on_full: None,
+        }
+    }
+
     /// Places a value at the end of the `Queue` if there is room or 
-    /// return a `QueueFullError` if full.
+    /// return a `QueueFullError` if full. An unbounded `Queue` grows 
+    /// instead of returning an error.
     /// ```
     /// use rsds::queue::Queue;
     ///
@@ -53,6 +126,12 @@ impl<T: Clone> Queue<T> {
     /// q.enqueue(42u32);
     /// ```
     pub fn enqueue(&mut self, val: T) -> Result<(), QueueFullError> {
+        if self.count == self.queue.capacity() && self.growable {
+            self.grow();
+        }
+
+        // a zero capacity queue is always full, so the modulo below never 
+        // divides by zero
         if self.count == self.queue.capacity() {
             Err(QueueFullError)
         } else {
@@ -60,10 +139,184 @@ impl<T: Clone> Queue<T> {
             self.count += 1;
             self.tail += 1;
             self.tail %= self.queue.capacity();
+
+            // an unbounded queue grows on the next enqueue, so reaching 
+            // capacity does not make it full
+            if !self.growable && self.count == self.queue.capacity() {
+                self.notify_full();
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Places a value at the end of the `Queue` like `enqueue`, returning 
+    /// the number of free slots left after the insert or a 
+    /// `QueueFullError` if full.
+    /// ```
+    /// use rsds::queue::{Queue, QueueFullError};
+    /// 
+    /// let mut q = Queue::<u32>::new(2);
+    /// 
+    /// assert_eq!(q.enqueue_checked(1u32), Ok(1));
+    /// assert_eq!(q.enqueue_checked(2u32), Ok(0));
+    /// assert_eq!(q.enqueue_checked(3u32), Err(QueueFullError));
+    /// ```
+    pub fn enqueue_checked(&mut self, val: T) -> Result<usize, QueueFullError> {
+        self.enqueue(val)?;
+
+        Ok(self.queue.capacity() - self.count)
+    }
+
+    /// Places a value at the end of the `Queue` if there is room, returning 
+    /// `true`. If the `Queue` is full the value is dropped and `false` is 
+    /// returned. Unlike `enqueue_overwrite`, the values already in the 
+    /// `Queue` are never evicted. An unbounded `Queue` grows instead of 
+    /// filling up, so it always returns `true`.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(1);
+    /// 
+    /// assert!(q.saturating_enqueue(1u32));
+    /// assert!(!q.saturating_enqueue(2u32));
+    /// assert_eq!(q.dequeue(), Some(1u32));
+    /// ```
+    pub fn saturating_enqueue(&mut self, val: T) -> bool {
+        self.enqueue(val).is_ok()
+    }
+
+    /// Places each value from `items` at the end of the `Queue` in order 
+    /// until the `Queue` is full. If every value fits `Ok(())` is 
+    /// returned, otherwise the values that did not fit are returned in 
+    /// their original order as the `Err` payload.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(3);
+    /// 
+    /// let ret = q.enqueue_batch(vec![1u32, 2u32, 3u32, 4u32, 5u32]);
+    /// assert_eq!(ret, Err(vec![4u32, 5u32]));
+    /// assert_eq!(q.dequeue(), Some(1u32));
+    /// ```
+    pub fn enqueue_batch(&mut self, items: Vec<T>) -> Result<(), Vec<T>> {
+        let mut items = items;
+        let room = if self.growable {
+            items.len()
+        } else {
+            self.queue.capacity() - self.count
+        };
+
+        let leftover = if items.len() > room {
+            items.split_off(room)
+        } else {
+            Vec::new()
+        };
+
+        for val in items {
+            // there is room for every remaining item so this can not fail
+            let _ = self.enqueue(val);
+        }
+
+        if leftover.is_empty() {
+            Ok(())
+        } else {
+            Err(leftover)
+        }
+    }
+
+    /// Moves every element of `other` onto the end of the `Queue` in 
+    /// front to back order, leaving `other` empty. If there is not enough 
+    /// room, as many elements as fit are moved, the rest are left in 
+    /// `other` and a `QueueFullError` is returned.
+    /// ```
+    /// use rsds::queue::{Queue, QueueFullError};
+    /// 
+    /// let mut q = Queue::<u32>::new(3);
+    /// let mut other = Queue::<u32>::new(3);
+    /// 
+    /// q.enqueue(1u32);
+    /// other.enqueue(2u32);
+    /// other.enqueue(3u32);
+    /// other.enqueue(4u32);
+    /// 
+    /// assert_eq!(q.append(&mut other), Err(QueueFullError));
+    /// assert_eq!(q.dequeue(), Some(1u32));
+    /// assert_eq!(other.dequeue(), Some(4u32));
+    /// ```
+    pub fn append(&mut self, other: &mut Queue<T>) -> Result<(), QueueFullError> {
+        if self.growable {
+            self.reserve(other.count);
+        }
+
+        // work out how many values fit before taking any out of `other`, 
+        // so a value is never dropped between the two queues
+        let fits = other.count.min(self.queue.capacity() - self.count);
+
+        for _ in 0..fits {
+            if let Some(val) = other.dequeue() {
+                let ret = self.enqueue(val);
+                debug_assert!(ret.is_ok(), "room was checked before moving");
+            }
+        }
+
+        if other.count > 0 {
+            Err(QueueFullError)
+        } else {
             Ok(())
         }
     }
 
+    /// Places a value at the end of the `Queue`, overwriting the value at 
+    /// the front if the `Queue` is full. The overwritten value is returned 
+    /// as `Some(T)`, or `None` if there was room for the new value. A 
+    /// `Queue` with a capacity of zero can not hold any value, so `val` 
+    /// itself is returned. An unbounded `Queue` grows rather than evicting, 
+    /// so `None` is always returned.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(2);
+    /// 
+    /// assert_eq!(q.enqueue_overwrite(1u32), None);
+    /// assert_eq!(q.enqueue_overwrite(2u32), None);
+    /// 
+    /// // Queue is full so the oldest value is evicted.
+    /// assert_eq!(q.enqueue_overwrite(3u32), Some(1u32));
+    /// assert_eq!(q.dequeue(), Some(2u32));
+    /// ```
+    pub fn enqueue_overwrite(&mut self, val: T) -> Option<T> {
+        if self.growable {
+            let _ = self.enqueue(val);
+            return None;
+        }
+
+        if self.queue.capacity() == 0 {
+            return Some(val);
+        }
+
+        let evicted = if self.count == self.queue.capacity() {
+            // head and tail share a slot when full, so the head moves on to 
+            // the next oldest value
+            self.head = (self.head + 1) % self.queue.capacity();
+            self.queue[self.tail].take()
+        } else {
+            self.count += 1;
+            None
+        };
+
+        self.queue[self.tail] = Some(val);
+        self.tail += 1;
+        self.tail %= self.queue.capacity();
+
+        // an eviction leaves the queue full rather than filling it
+        if !self.growable && evicted.is_none() && self.count == self.queue.capacity() {
+            self.notify_full();
+        }
+
+        evicted
+    }
+
     /// Removes a value from the front of the `Queue` as an `Option<T>` or `None`.
     /// if the Queue is empty.
     /// ```
@@ -82,218 +335,3480 @@ impl<T: Clone> Queue<T> {
         if self.count == 0 {
             None
         } else {
-            
-            let head = (self.tail + self.queue.capacity() - self.count) % self.queue.capacity();
-            let ret = self.queue[head].clone();
-            
-            self.queue[head] = None;
+            let ret = self.queue[self.head].take();
+
+            self.head = (self.head + 1) % self.queue.capacity();
             self.count -= 1;
 
             ret
         }
     }
 
-    /// Returns an immutable reference to front of Queue.
+    /// Removes up to `n` values from the front of the `Queue`, returning 
+    /// them in front to back order. If the `Queue` holds fewer than `n` 
+    /// values, every value is removed.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// q.enqueue(3u32);
+    /// 
+    /// assert_eq!(q.dequeue_n(2), vec![1u32, 2u32]);
+    /// assert_eq!(q.dequeue_n(2), vec![3u32]);
+    /// ```
+    pub fn dequeue_n(&mut self, n: usize) -> Vec<T> {
+        let n = n.min(self.count);
+        let mut ret = Vec::with_capacity(n);
+
+        for _ in 0..n {
+            if let Some(val) = self.dequeue() {
+                ret.push(val);
+            }
+        }
+
+        ret
+    }
+
+    /// Removes values from the front of the `Queue` for as long as `pred` 
+    /// returns `true`, returning them in front to back order. The first 
+    /// value for which `pred` returns `false` stays at the front.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// q.enqueue(5u32);
+    /// q.enqueue(3u32);
+    /// 
+    /// assert_eq!(q.drain_while(|x| *x < 4), vec![1u32, 2u32]);
+    /// assert_eq!(q.peek(), Some(&5u32));
+    /// ```
+    pub fn drain_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut ret = Vec::new();
+
+        while self.peek().is_some_and(&mut pred) {
+            if let Some(val) = self.dequeue() {
+                ret.push(val);
+            }
+        }
+
+        ret
+    }
+
+    /// Returns an immutable reference to front of Queue, or `None` if the 
+    /// Queue is empty.
     /// ```
     /// use rsds::queue::Queue;
     /// 
     /// // Creates an empty Queue capable of holding up to 5 u32 elements. 
     /// let mut q = Queue::<u32>::new(5);
+    /// assert_eq!(q.peek(), None);
     ///
     /// q.enqueue(42u32);
     /// 
     /// let peeked = q.peek();
-    /// assert_eq!(peeked, &Some(42u32));
+    /// assert_eq!(peeked, Some(&42u32));
     /// ``` 
-    pub fn peek(&self) -> &Option<T> {
-        let head = (self.tail + self.queue.capacity() - self.count) % self.queue.capacity();
-        self.queue.get(head).unwrap()
-    }
-}
-
-#[cfg(test)]
-mod test_queue {
-    use crate::queue::*;
-
-    #[test]
-    fn queue_new_fills_inner_vec() {
-        let queue = Queue::<u32>::new(5);
+    pub fn peek(&self) -> Option<&T> {
+        if self.count == 0 {
+            return None;
+        }
 
-        assert_eq!(queue.queue.len(), 5);
+        self.queue[self.head].as_ref()
     }
 
-    #[test]
-    fn queue_new_creates_queue_with_correct_capacity() {
-        let queue = Queue::<u32>::new(5);
+    /// Returns an immutable reference to the value `index` places from the 
+    /// front of the Queue without removing it, where index 0 is the front. 
+    /// Returns `None` if `index` is not less than `len()`.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// assert_eq!(q.peek_at(0), Some(&1u32));
+    /// assert_eq!(q.peek_at(1), Some(&2u32));
+    /// assert_eq!(q.peek_at(2), None);
+    /// ```
+    pub fn peek_at(&self, index: usize) -> Option<&T> {
+        if index >= self.count {
+            return None;
+        }
 
-        assert_eq!(queue.queue.capacity(), 5);
+        self.queue[(self.head + index) % self.queue.capacity()].as_ref()
     }
 
-    #[test]
-    fn queue_new_creates_inner_vec_filled_with_none() {
-        let queue = Queue::<u32>::new(5);
-
-        for item in &queue.queue {
-            assert_eq!(item, &None);
+    /// Returns an immutable reference to the back of the Queue, the most 
+    /// recently enqueued value, or `None` if the Queue is empty.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// assert_eq!(q.peek_back(), None);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// assert_eq!(q.peek_back(), Some(&2u32));
+    /// assert_eq!(q.peek(), Some(&1u32));
+    /// ```
+    pub fn peek_back(&self) -> Option<&T> {
+        if self.count == 0 {
+            return None;
         }
 
-        assert_eq!(queue.queue.capacity(), 5);
-    } 
+        let back = (self.tail + self.queue.capacity() - 1) % self.queue.capacity();
+        self.queue[back].as_ref()
+    }
 
-    #[test]
-    fn queue_enqueue_places_item_at_tail() {
-        let mut queue = Queue::new(5);
+    /// Returns references to the front and back of the Queue as a pair, or 
+    /// `None` if the Queue is empty. Both references are to the same value 
+    /// when the Queue holds a single value.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// assert_eq!(q.ends(), None);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// q.enqueue(3u32);
+    /// 
+    /// assert_eq!(q.ends(), Some((&1u32, &3u32)));
+    /// ```
+    pub fn ends(&self) -> Option<(&T, &T)> {
+        Some((self.peek()?, self.peek_back()?))
+    }
 
-        let mut ret = queue.enqueue(1u32);
-        assert_eq!(ret, Ok(()));
+    /// Returns the number of elements in the `Queue` as a `usize`.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// assert_eq!(q.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.count
+    }
 
-        ret = queue.enqueue(2u32);
-        assert_eq!(ret, Ok(()));
+    /// Returns the max capacity of the `Queue` as a `usize`.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let q = Queue::<u32>::new(5);
+    /// 
+    /// assert_eq!(q.capacity(), 5);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
 
-        ret = queue.enqueue(3u32);
-        assert_eq!(ret, Ok(()));
+    /// Returns the number of free slots left in the `Queue` as a `usize`. 
+    /// For a bounded `Queue` this is how many more values can be enqueued 
+    /// before `enqueue` returns a `QueueFullError`. An unbounded `Queue` 
+    /// never errors, so for it this is the room left before the next 
+    /// reallocation.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// 
+    /// assert_eq!(q.remaining(), 4);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.queue.capacity() - self.count
+    }
 
-        ret = queue.enqueue(4u32);
-        assert_eq!(ret, Ok(()));
+    /// Removes all elements from the `Queue`, resetting every slot to 
+    /// `None`. The capacity of the `Queue` is unchanged.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// q.clear();
+    /// assert!(q.is_empty());
+    /// assert_eq!(q.dequeue(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        for slot in self.queue.iter_mut() {
+            *slot = None;
+        }
 
-        assert_eq!(&queue.queue[queue.tail - 1].unwrap(), &4u32);
+        self.count = 0;
+        self.head = 0;
+        self.tail = 0;
     }
 
-    #[test]
-    fn queue_tail_and_head_equal_when_full() {
-        let mut queue = Queue::new(5);
-
-        let mut ret = queue.enqueue(1u32);
-        assert_eq!(ret, Ok(()));
+    /// Removes every element from the `Queue`, returning them front to 
+    /// back as an iterator. The `Queue` is empty once the iterator is 
+    /// dropped, even if not every element was consumed.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// let drained: Vec<u32> = q.drain().collect();
+    /// assert_eq!(drained, vec![1u32, 2u32]);
+    /// assert!(q.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> QueueDrain<'_, T> {
+        QueueDrain { queue: self }
+    }
 
-        ret = queue.enqueue(2u32);
-        assert_eq!(ret, Ok(()));
+    /// Removes all elements from the `Queue` and changes its max capacity 
+    /// to `new_size`, leaving an empty `Queue` as if it had been created 
+    /// with `Queue::new(new_size)`.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.reset_with_capacity(2);
+    /// 
+    /// assert!(q.is_empty());
+    /// assert_eq!(q.capacity(), 2);
+    /// ```
+    pub fn reset_with_capacity(&mut self, new_size: usize) {
+        let mut queue = Vec::with_capacity(new_size);
 
-        ret = queue.enqueue(3u32);
-        assert_eq!(ret, Ok(()));
+        for _ in 0..queue.capacity() {
+            queue.push(None);
+        }
 
-        ret = queue.enqueue(4u32);
-        assert_eq!(ret, Ok(()));
+        self.queue = queue;
+        self.count = 0;
+        self.head = 0;
+        self.tail = 0;
+    }
 
-        ret = queue.enqueue(4u32);
-        assert_eq!(ret, Ok(()));
+    /// Shrinks the capacity of the `Queue` to `min_capacity`, or to the 
+    /// number of elements if that is larger. Elements keep their front to 
+    /// back order. Has no effect if `min_capacity` is greater than or 
+    /// equal to the current capacity.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(10);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// q.shrink_to(4);
+    /// assert_eq!(q.capacity(), 4);
+    /// 
+    /// q.shrink_to(0);
+    /// assert_eq!(q.capacity(), 2);
+    /// assert_eq!(q.dequeue(), Some(1u32));
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if min_capacity >= self.queue.capacity() {
+            return;
+        }
 
-        let head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(head, queue.tail);
+        self.relinearize(min_capacity.max(self.count));
     }
 
-    #[test]
-    fn queue_count_zero_when_empty() {
-        let mut queue = Queue::new(5);
-
+    /// Reserves room for at least `additional` more elements beyond the 
+    /// current length, so a full `Queue` accepts `additional` more values 
+    /// before returning a `QueueFullError`. Elements keep their front to 
+    /// back order and no value is overwritten. Has no effect if the 
+    /// capacity is already large enough. Panics if the new capacity 
+    /// overflows `usize`.
+    /// ```
+    /// use rsds::queue::{Queue, QueueFullError};
+    /// 
+    /// let mut q = Queue::<u32>::new(1);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.reserve(1);
+    /// 
+    /// assert_eq!(q.enqueue(2u32), Ok(()));
+    /// assert_eq!(q.enqueue(3u32), Err(QueueFullError));
+    /// assert_eq!(q.dequeue(), Some(1u32));
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.count.checked_add(additional).expect("capacity overflow");
+
+        if required <= self.queue.capacity() {
+            return;
+        }
+
+        self.relinearize(required);
+    }
+
+    /// Moves the value at the front of the `Queue` to the back without 
+    /// dequeuing it, so this works even when the `Queue` is full.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::from([1u32, 2u32, 3u32]);
+    /// 
+    /// q.rotate();
+    /// assert_eq!(q.to_vec(), vec![2u32, 3u32, 1u32]);
+    /// ```
+    pub fn rotate(&mut self) {
+        self.rotate_n(1);
+    }
+
+    /// Moves the value at the front of the `Queue` to the back `n` times. 
+    /// Rotating by a multiple of `len()` leaves the order unchanged.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::from([1u32, 2u32, 3u32]);
+    /// 
+    /// q.rotate_n(5);
+    /// assert_eq!(q.to_vec(), vec![3u32, 1u32, 2u32]);
+    /// ```
+    pub fn rotate_n(&mut self, n: usize) {
+        if self.count == 0 {
+            return;
+        }
+
+        let capacity = self.queue.capacity();
+        let n = n % self.count;
+
+        if self.count == capacity {
+            // no empty slots, so the head and tail move together
+            self.head = (self.head + n) % capacity;
+            self.tail = self.head;
+        } else {
+            for _ in 0..n {
+                self.queue[self.tail] = self.queue[self.head].take();
+                self.head = (self.head + 1) % capacity;
+                self.tail = (self.tail + 1) % capacity;
+            }
+        }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, removing the 
+    /// rest. The remaining elements keep their front to back order.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// q.enqueue(3u32);
+    /// 
+    /// q.retain(|x| x % 2 == 1);
+    /// 
+    /// assert_eq!(q.dequeue(), Some(1u32));
+    /// assert_eq!(q.dequeue(), Some(3u32));
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        if self.count == 0 {
+            return;
+        }
+
+        let capacity = self.queue.capacity();
+        let head = self.head;
+        let mut kept = 0;
+
+        for i in 0..self.count {
+            // kept elements only ever move towards the head, into slots 
+            // that have already been visited
+            if let Some(val) = self.queue[(head + i) % capacity].take() {
+                if f(&val) {
+                    self.queue[(head + kept) % capacity] = Some(val);
+                    kept += 1;
+                }
+            }
+        }
+
+        self.count = kept;
+        self.tail = (head + kept) % capacity;
+    }
+
+    /// Consumes the `Queue`, returning its elements in front to back 
+    /// order as a `Vec`.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// assert_eq!(q.into_vec(), vec![1u32, 2u32]);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut v = Vec::with_capacity(self.count);
+
+        while let Some(val) = self.dequeue() {
+            v.push(val);
+        }
+
+        v
+    }
+
+    /// Returns a copy of the elements of the `Queue` in front to back 
+    /// order as a `Vec`, leaving the `Queue` untouched.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// assert_eq!(q.to_vec(), vec![1u32, 2u32]);
+    /// assert_eq!(q.len(), 2);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Returns `true` if the `Queue` contains an element equal to `val`.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(42u32);
+    /// 
+    /// assert!(q.contains(&42u32));
+    /// assert!(!q.contains(&7u32));
+    /// ```
+    pub fn contains(&self, val: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|x| x == val)
+    }
+
+    /// Returns `true` if the `Queue` contains no elements.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// assert!(q.is_empty());
+    /// 
+    /// q.enqueue(42u32);
+    /// assert!(!q.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns `true` if the next `enqueue` will return a 
+    /// `QueueFullError`, which is when a bounded `Queue` is at capacity. 
+    /// An unbounded `Queue` grows rather than erroring, so it is never full.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(2);
+    /// 
+    /// q.enqueue(1u32);
+    /// assert!(!q.is_full());
+    /// 
+    /// q.enqueue(2u32);
+    /// assert!(q.is_full());
+    /// 
+    /// let mut u = Queue::<u32>::unbounded();
+    /// 
+    /// u.enqueue(1u32);
+    /// assert!(!u.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        !self.growable && self.count == self.queue.capacity()
+    }
+
+    /// Returns how full the `Queue` is as `len / capacity`, in the range 
+    /// `0.0` to `1.0`. A zero capacity `Queue` has no free slots, so it 
+    /// reports `1.0` rather than dividing by zero.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(4);
+    /// assert_eq!(q.utilization(), 0.0);
+    /// 
+    /// q.enqueue(1u32);
+    /// assert_eq!(q.utilization(), 0.25);
+    /// 
+    /// assert_eq!(Queue::<u32>::new(0).utilization(), 1.0);
+    /// ```
+    pub fn utilization(&self) -> f32 {
+        if self.queue.capacity() == 0 {
+            return 1.0;
+        }
+
+        self.count as f32 / self.queue.capacity() as f32
+    }
+
+    /// Returns an iterator over the `Queue` in front to back order, the 
+    /// same order values are dequeued in. The iterator is double ended, so 
+    /// `iter().rev()` walks the `Queue` from back to front.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// q.enqueue(3u32);
+    /// 
+    /// assert!(q.iter().eq([1u32, 2u32, 3u32].iter()));
+    /// assert!(q.iter().rev().eq([3u32, 2u32, 1u32].iter()));
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        let capacity = self.queue.capacity();
+        let head = self.head;
+
+        (0..self.count).filter_map(move |i| self.queue[(head + i) % capacity].as_ref())
+    }
+
+    /// Returns an iterator of mutable references over the `Queue` in 
+    /// front to back order, allowing elements to be updated in place.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// for val in q.iter_mut() {
+    ///     *val *= 10;
+    /// }
+    /// 
+    /// assert_eq!(q.dequeue(), Some(10u32));
+    /// assert_eq!(q.dequeue(), Some(20u32));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let head = self.head;
+
+        // occupied slots run from head and may wrap around to the start, 
+        // every other slot is None
+        let (wrapped, front) = self.queue.split_at_mut(head);
+
+        front.iter_mut().chain(wrapped.iter_mut()).filter_map(Option::as_mut)
+    }
+
+    /// Registers a callback that runs whenever an enqueue fills the last 
+    /// free slot of the `Queue`. The callback runs once per transition to 
+    /// full and runs again only after space has been freed and the 
+    /// `Queue` fills up once more. Registering a new callback replaces the 
+    /// previous one. Clones of the `Queue` do not carry the callback. An 
+    /// unbounded `Queue` is never full, so its callback never runs. 
+    /// The callback is stored in the `Queue`, so it must be `Send + Sync` 
+    /// to keep the `Queue` `Send` and `Sync`, and `'static` since it may 
+    /// outlive any borrowed data.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// 
+    /// let fills = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&fills);
+    /// 
+    /// let mut q = Queue::<u32>::new(2);
+    /// q.on_full(move || {
+    ///     counter.fetch_add(1, Ordering::SeqCst);
+    /// });
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// assert_eq!(fills.load(Ordering::SeqCst), 1);
+    /// ```
+    pub fn on_full<F: FnMut() + Send + Sync + 'static>(&mut self, cb: F) {
+        self.on_full = Some(Box::new(cb));
+    }
+
+    /// Returns the slot index of the front of the `Queue`. Only intended 
+    /// for diagnosing wraparound, as the layout of the internal buffer is 
+    /// not part of the public API.
+    #[cfg(feature = "internals")]
+    pub fn debug_head(&self) -> usize {
+        self.head
+    }
+
+    /// Returns the slot index the next enqueued value will be written to. 
+    /// Only intended for diagnosing wraparound.
+    #[cfg(feature = "internals")]
+    pub fn debug_tail(&self) -> usize {
+        self.tail
+    }
+
+    /// Runs the `on_full` callback, if one is registered.
+    fn notify_full(&mut self) {
+        if let Some(cb) = self.on_full.as_mut() {
+            cb();
+        }
+    }
+
+    /// Doubles the capacity of the `Queue`.
+    fn grow(&mut self) {
+        self.relinearize((self.queue.capacity() * 2).max(1));
+    }
+
+    /// Moves the elements into a new buffer of `new_size` slots in front 
+    /// to back order, so the head is at slot zero and the tail follows 
+    /// the last element. `new_size` must be at least `count`.
+    fn relinearize(&mut self, new_size: usize) {
+        let capacity = self.queue.capacity();
+        let mut queue = Vec::<Option<T>>::with_capacity(new_size);
+
+        for i in 0..self.count {
+            queue.push(self.queue[(self.head + i) % capacity].take());
+        }
+
+        for _ in queue.len()..queue.capacity() {
+            queue.push(None);
+        }
+
+        self.queue = queue;
+        self.head = 0;
+        self.tail = if self.queue.capacity() == 0 {
+            0
+        } else {
+            self.count % self.queue.capacity()
+        };
+    }
+}
+
+impl<T: Clone> Clone for Queue<T> {
+    /// Returns a copy of the `Queue` with the same elements in the same 
+    /// slots. Any `on_full` callback is not cloned.
+    fn clone(&self) -> Self {
+        Queue {
+            queue: self.queue.clone(),
+            count: self.count,
+            head: self.head,
+            tail: self.tail,
+            growable: self.growable,
+            on_full: None,
+        }
+    }
+}
+
+impl<T> Default for Queue<T> {
+    /// Creates an empty `Queue` with a max capacity of zero. The `Queue` 
+    /// is full as soon as it is created, so `enqueue` returns a 
+    /// `QueueFullError` until it is given room with `reset_with_capacity`.
+    /// ```
+    /// use rsds::queue::{Queue, QueueFullError};
+    /// 
+    /// let mut q = Queue::<u32>::default();
+    /// assert_eq!(q.enqueue(1u32), Err(QueueFullError));
+    /// 
+    /// q.reset_with_capacity(1);
+    /// assert_eq!(q.enqueue(1u32), Ok(()));
+    /// ```
+    fn default() -> Self {
+        Queue::new(0)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Queue<T> {
+    /// Creates a `Queue` from an array, enqueuing elements in array order 
+    /// so the first element is at the front. The capacity is set to `N`, 
+    /// so the resulting `Queue` is full.
+    /// ```
+    /// use rsds::queue::{Queue, QueueFullError};
+    /// 
+    /// let mut q = Queue::from([1u32, 2u32, 3u32]);
+    /// 
+    /// assert_eq!(q.enqueue(4u32), Err(QueueFullError));
+    /// assert_eq!(q.dequeue(), Some(1u32));
+    /// ```
+    fn from(arr: [T; N]) -> Self {
+        let mut queue = Queue::new(N);
+
+        for val in arr {
+            // the queue has exactly enough room so this can not fail
+            let _ = queue.enqueue(val);
+        }
+
+        queue
+    }
+}
+
+/// A draining iterator over the elements of a `Queue`, yielding them from 
+/// front to back. Created by calling `drain` on a `Queue`.
+pub struct QueueDrain<'a, T> {
+    queue: &'a mut Queue<T>,
+}
+
+impl<T> Iterator for QueueDrain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.count, Some(self.queue.count))
+    }
+}
+
+impl<T> Drop for QueueDrain<'_, T> {
+    fn drop(&mut self) {
+        self.queue.clear();
+    }
+}
+
+impl<T: PartialEq> PartialEq for Queue<T> {
+    /// Two queues are equal when they hold the same elements in the same 
+    /// front to back order. Capacity and the internal position of the 
+    /// elements are not compared.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut a = Queue::<u32>::new(3);
+    /// let mut b = Queue::<u32>::new(5);
+    /// 
+    /// a.enqueue(1u32);
+    /// a.enqueue(2u32);
+    /// a.dequeue();
+    /// 
+    /// b.enqueue(2u32);
+    /// 
+    /// assert!(a == b);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Queue<T> {}
+
+impl<T: PartialOrd> PartialOrd for Queue<T> {
+    /// Queues are ordered lexicographically by their elements from front 
+    /// to back. The first differing element decides the order, and if one 
+    /// queue is a prefix of the other the shorter queue is less. Capacity 
+    /// and the internal position of the elements are not compared.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let a = Queue::from([1u32, 2u32]);
+    /// let b = Queue::from([1u32, 3u32]);
+    /// 
+    /// assert!(a < b);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for Queue<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for Queue<T> {
+    /// Hashes the number of elements followed by each element in front to 
+    /// back order. Empty slots, capacity and the internal position of the 
+    /// elements are not hashed, so queues that compare equal hash equally.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.count.hash(state);
+
+        for val in self.iter() {
+            val.hash(state);
+        }
+    }
+}
+
+
+impl<T: fmt::Display> fmt::Display for Queue<T> {
+    /// Formats the elements of the `Queue` from front to back as a 
+    /// bracketed list.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// q.enqueue(3u32);
+    /// 
+    /// assert_eq!(q.to_string(), "[1, 2, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+
+        for (i, val) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", val)?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Queue<T> {
+    /// Formats the `Queue` showing its size, capacity, and elements in 
+    /// front to back order.
+    /// ```
+    /// use rsds::queue::Queue;
+    /// 
+    /// let mut q = Queue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// assert_eq!(format!("{:?}", q), "Queue { size: 2, capacity: 5, elements: [1, 2] }");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements: Vec<&T> = self.iter().collect();
+
+        f.debug_struct("Queue")
+            .field("size", &self.count)
+            .field("capacity", &self.queue.capacity())
+            .field("elements", &elements)
+            .finish()
+    }
+}
+
+/// Enqueues bytes in order until the `Queue` is full, returning the 
+/// number enqueued, so a write may be short. Once the `Queue` is full 
+/// `write` returns `Ok(0)`, which `write_all` reports as 
+/// `ErrorKind::WriteZero`.
+/// ```
+/// use rsds::queue::Queue;
+/// use std::io::Write;
+/// 
+/// let mut q = Queue::<u8>::new(3);
+/// 
+/// assert_eq!(q.write(b"abcd").unwrap(), 3);
+/// assert_eq!(q.dequeue(), Some(b'a'));
+/// ```
+#[cfg(feature = "std")]
+impl std::io::Write for Queue<u8> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut written = 0;
+
+        for byte in buf {
+            if self.enqueue(*byte).is_err() {
+                break;
+            }
+
+            written += 1;
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Dequeues bytes into `buf` in front to back order, returning the number 
+/// of bytes read. Returns `Ok(0)` once the `Queue` is empty.
+/// ```
+/// use rsds::queue::Queue;
+/// use std::io::{Read, Write};
+/// 
+/// let mut q = Queue::<u8>::new(5);
+/// q.write_all(b"abc").unwrap();
+/// 
+/// let mut buf = [0u8; 2];
+/// assert_eq!(q.read(&mut buf).unwrap(), 2);
+/// assert_eq!(&buf, b"ab");
+/// ```
+#[cfg(feature = "std")]
+impl std::io::Read for Queue<u8> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut read = 0;
+
+        for slot in buf.iter_mut() {
+            match self.dequeue() {
+                Some(byte) => *slot = byte,
+                None => break,
+            }
+
+            read += 1;
+        }
+
+        Ok(read)
+    }
+}
+
+/// Serializes the `Queue` as its capacity, its elements from front to 
+/// back and, for an unbounded `Queue`, a `growable` flag. Empty slots are 
+/// not serialized, and neither is the `on_full` callback.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Queue<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let elements: Vec<&T> = self.iter().collect();
+
+        let len = 2 + usize::from(self.growable);
+        let mut state = serializer.serialize_struct("Queue", len)?;
+        state.serialize_field("capacity", &self.queue.capacity())?;
+        state.serialize_field("elements", &elements)?;
+        if self.growable {
+            state.serialize_field("growable", &true)?;
+        } else {
+            state.skip_field("growable")?;
+        }
+        state.end()
+    }
+}
+
+/// Deserializes a `Queue` from its capacity, its elements from front to 
+/// back and an optional `growable` flag, which makes the `Queue` 
+/// unbounded. An error is returned if there are more elements than 
+/// capacity or if the capacity can not be allocated.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Queue<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = crate::serde_support::Repr::<T>::deserialize(deserializer)?;
+        let growable = repr.growable;
+
+        repr.build(
+            "queue",
+            |cap| Queue::try_new(cap).map(|q| Queue { growable, ..q }),
+            |c, val| c.enqueue(val),
+        )
+    }
+}
+
+impl<T> Collection<T> for Queue<T> {
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
+    fn is_full(&self) -> bool {
+        Queue::is_full(self)
+    }
+
+    fn clear(&mut self) {
+        Queue::clear(self)
+    }
+}
+
+/// A ring buffer `Queue` that holds up to `N` elements of type `T` inline, 
+/// without any heap allocation. It behaves like a `Queue` created with 
+/// `Queue::new(N)`, returning a `QueueFullError` once `N` elements are 
+/// waiting to be dequeued.
+pub struct ArrayQueue<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    count: usize,
+    head: usize,
+    tail: usize,
+}
+
+impl<T, const N: usize> ArrayQueue<T, N> {
+    /// Create a new empty `ArrayQueue` with a max capacity of `N`.
+    /// ```
+    /// use rsds::queue::ArrayQueue;
+    /// 
+    /// // Empty queue capable of holding up to 5 u32 elements.
+    /// let q = ArrayQueue::<u32, 5>::new();
+    /// ```
+    pub fn new() -> Self {
+        ArrayQueue {
+            data: [const { MaybeUninit::uninit() }; N],
+            count: 0,
+            head: 0,
+            tail: 0,
+        }
+    }
+
+    /// Places a value at the end of the queue if there is room or return 
+    /// a `QueueFullError` if full.
+    /// ```
+    /// use rsds::queue::{ArrayQueue, QueueFullError};
+    /// 
+    /// let mut q = ArrayQueue::<u32, 1>::new();
+    /// 
+    /// assert_eq!(q.enqueue(1u32), Ok(()));
+    /// assert_eq!(q.enqueue(2u32), Err(QueueFullError));
+    /// ```
+    pub fn enqueue(&mut self, val: T) -> Result<(), QueueFullError> {
+        // a zero capacity queue is always full, so the modulo below never 
+        // divides by zero
+        if self.count == N {
+            return Err(QueueFullError);
+        }
+
+        self.data[self.tail].write(val);
+        self.tail = (self.tail + 1) % N;
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// Removes a value from the front of the queue as an `Option<T>` or 
+    /// `None` if the queue is empty.
+    /// ```
+    /// use rsds::queue::ArrayQueue;
+    /// 
+    /// let mut q = ArrayQueue::<u32, 5>::new();
+    /// 
+    /// q.enqueue(1u32);
+    /// q.enqueue(2u32);
+    /// 
+    /// assert_eq!(q.dequeue(), Some(1u32));
+    /// ```
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.count == 0 {
+            return None;
+        }
+
+        // SAFETY: the `count` slots starting at `head` are initialized, and 
+        // moving `head` past this slot means it is never read or dropped again
+        let ret = unsafe { self.data[self.head].assume_init_read() };
+
+        self.head = (self.head + 1) % N;
+        self.count -= 1;
+
+        Some(ret)
+    }
+
+    /// Returns a reference to the value at the front of the queue without 
+    /// removing it, or `None` if the queue is empty.
+    /// ```
+    /// use rsds::queue::ArrayQueue;
+    /// 
+    /// let mut q = ArrayQueue::<u32, 5>::new();
+    /// assert_eq!(q.peek(), None);
+    /// 
+    /// q.enqueue(1u32);
+    /// assert_eq!(q.peek(), Some(&1u32));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        if self.count == 0 {
+            return None;
+        }
+
+        // SAFETY: `head` is initialized while the queue is not empty
+        Some(unsafe { self.data[self.head].assume_init_ref() })
+    }
+
+    /// Returns the number of elements in the queue as a `usize`.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the max capacity of the queue, which is always `N`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns `true` if the queue holds `N` elements, meaning the next 
+    /// `enqueue` will return a `QueueFullError`.
+    pub fn is_full(&self) -> bool {
+        self.count == N
+    }
+}
+
+impl<T, const N: usize> Default for ArrayQueue<T, N> {
+    fn default() -> Self {
+        ArrayQueue::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayQueue<T, N> {
+    fn drop(&mut self) {
+        for i in 0..self.count {
+            // SAFETY: the `count` slots starting at `head` are initialized 
+            // and are not used again after the queue is dropped
+            unsafe { self.data[(self.head + i) % N].assume_init_drop() };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_queue {
+    use crate::queue::*;
+
+    #[test]
+    fn queue_new_fills_inner_vec() {
+        let queue = Queue::<u32>::new(5);
+
+        assert_eq!(queue.queue.len(), 5);
+    }
+
+    #[test]
+    fn queue_new_creates_queue_with_correct_capacity() {
+        let queue = Queue::<u32>::new(5);
+
+        assert_eq!(queue.queue.capacity(), 5);
+    }
+
+    #[test]
+    fn queue_new_creates_inner_vec_filled_with_none() {
+        let queue = Queue::<u32>::new(5);
+
+        for item in &queue.queue {
+            assert_eq!(item, &None);
+        }
+
+        assert_eq!(queue.queue.capacity(), 5);
+    } 
+
+    #[test]
+    fn queue_enqueue_places_item_at_tail() {
+        let mut queue = Queue::new(5);
+
+        let mut ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(3u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(&queue.queue[queue.tail - 1].unwrap(), &4u32);
+    }
+
+    #[test]
+    fn queue_tail_and_head_equal_when_full() {
+        let mut queue = Queue::new(5);
+
+        let mut ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(3u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        let head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(head, queue.tail);
+    }
+
+    #[test]
+    fn queue_count_zero_when_empty() {
+        let mut queue = Queue::new(5);
+
+        let mut ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(3u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        let mut ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(2u32));
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(3u32));
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(4u32));
+
+        assert_eq!(queue.count, 0);
+    }
+
+    #[test]
+    fn queue_enqueue_returns_queuefullerror_when_already_full() {
+        let mut queue = Queue::new(5);
+
+        let mut ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(3u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(5u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(6u32);
+        assert_eq!(ret, Err(QueueFullError));
+    }
+
+    #[test]
+    fn queue_peek_when_empty_returns_none() {
+        let mut queue = Queue::new(3);
+        assert_eq!(queue.peek(), None);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.peek(), Some(&1u32));
+
+        queue.dequeue();
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    fn queue_peek_back_returns_most_recent() {
+        let mut queue = Queue::new(5);
+        assert_eq!(queue.peek_back(), None);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(queue.peek_back(), Some(&i));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+        assert_eq!(queue.peek_back(), Some(&3u32));
+
+        queue.dequeue();
+        assert_eq!(queue.peek_back(), None);
+    }
+
+    #[test]
+    fn queue_peek_back_after_wraparound() {
+        let mut queue = Queue::new(3);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // tail wraps to slot zero, so the back is in the last slot
+        assert_eq!(queue.tail, 0);
+        assert_eq!(queue.peek_back(), Some(&3u32));
+
+        queue.dequeue();
+
+        let ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.peek_back(), Some(&4u32));
+        assert_eq!(queue.peek(), Some(&2u32));
+    }
+
+    #[test]
+    fn queue_ends_after_wraparound() {
+        let mut queue = Queue::new(3);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+
+        let ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        // logical [2, 3, 4] stored as [4, 2, 3]
+        assert_eq!(queue.ends(), Some((&2u32, &4u32)));
+    }
+
+    #[test]
+    fn queue_ends_single_and_empty() {
+        let mut queue = Queue::new(3);
+        assert_eq!(queue.ends(), None);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        let (front, back) = queue.ends().unwrap();
+        assert!(core::ptr::eq(front, back));
+        assert_eq!(*front, 1u32);
+
+        queue.dequeue();
+        assert_eq!(queue.ends(), None);
+    }
+
+    #[test]
+    fn queue_peek_returns_ref_to_head() {
+        let mut queue = Queue::new(5);
+
+        let mut ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        let mut peek = queue.peek();
+        let mut head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(peek, queue.queue[head].as_ref());
+
+        ret = queue.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+
+        peek = queue.peek();
+        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(peek, queue.queue[head].as_ref());
+
+        ret = queue.enqueue(3u32);
+        assert_eq!(ret, Ok(()));
+
+        peek = queue.peek();
+        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(peek, queue.queue[head].as_ref());
+
+        ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        peek = queue.peek();
+        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(peek, queue.queue[head].as_ref());
+
+        let mut ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+
+        peek = queue.peek();
+        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(peek, queue.queue[head].as_ref());
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(2u32));
+
+        peek = queue.peek();
+        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(peek, queue.queue[head].as_ref());
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(3u32));
+
+        peek = queue.peek();
+        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(peek, queue.queue[head].as_ref());
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(4u32));
+
+        peek = queue.peek();
+        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
+        assert_eq!(peek, queue.queue[head].as_ref());
+    }
+
+    #[test]
+    fn queue_is_empty_tracks_enqueue_and_dequeue() {
+        let mut queue = Queue::new(5);
+        assert!(queue.is_empty());
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+        assert!(!queue.is_empty());
+
+        let ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn queue_is_full_exactly_at_capacity() {
+        let mut queue = Queue::new(3);
+        assert!(!queue.is_full());
+
+        let mut ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+        assert!(!queue.is_full());
+
+        ret = queue.enqueue(3u32);
+        assert_eq!(ret, Ok(()));
+        assert!(queue.is_full());
+
+        ret = queue.enqueue(4u32);
+        assert_eq!(ret, Err(QueueFullError));
+        assert!(queue.is_full());
+
+        let ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+        assert!(!queue.is_full());
+    }
+
+    #[test]
+    fn queue_clear_resets_slots_and_preserves_capacity() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=5u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+
+        let ret = queue.enqueue(6u32);
+        assert_eq!(ret, Ok(()));
+
+        queue.clear();
+        assert_eq!(queue.count, 0);
+        assert_eq!(queue.tail, 0);
+        assert_eq!(queue.queue.capacity(), 5);
+
+        for item in &queue.queue {
+            assert_eq!(item, &None);
+        }
+
+        let ret = queue.enqueue(7u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.queue[0], Some(7u32));
+
+        let ret = queue.dequeue();
+        assert_eq!(ret, Some(7u32));
+    }
+
+    #[test]
+    fn queue_capacity_unchanged_by_enqueue_dequeue_clear() {
+        let mut queue = Queue::new(5);
+        assert_eq!(queue.capacity(), 5);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.capacity(), 5);
+
+        let ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+        assert_eq!(queue.capacity(), 5);
+
+        let ret = queue.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+
+        queue.clear();
+        assert_eq!(queue.capacity(), 5);
+    }
+
+    #[test]
+    fn queue_debug_shows_logical_order_after_wraparound() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=5u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(2u32));
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(3u32));
+
+        let ret = queue.enqueue(6u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(
+            format!("{:?}", queue),
+            "Queue { size: 3, capacity: 5, elements: [4, 5, 6] }"
+        );
+    }
+
+    #[test]
+    fn queue_debug_when_empty() {
+        let queue = Queue::<u32>::new(5);
+
+        assert_eq!(
+            format!("{:?}", queue),
+            "Queue { size: 0, capacity: 5, elements: [] }"
+        );
+    }
+
+    #[test]
+    fn queue_clone_behaves_identically_after_wraparound() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=5u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(2u32));
+
+        let mut enq = queue.enqueue(6u32);
+        assert_eq!(enq, Ok(()));
+
+        let mut cloned = queue.clone();
+        assert_eq!(cloned.queue, queue.queue);
+        assert_eq!(cloned.queue.capacity(), queue.queue.capacity());
+        assert_eq!(cloned.count, queue.count);
+        assert_eq!(cloned.tail, queue.tail);
+
+        enq = queue.enqueue(7u32);
+        assert_eq!(enq, Ok(()));
+
+        enq = cloned.enqueue(7u32);
+        assert_eq!(enq, Ok(()));
+
+        for _ in 0..6 {
+            assert_eq!(cloned.dequeue(), queue.dequeue());
+        }
+    }
+
+    #[test]
+    fn queue_eq_ignores_tail_offset() {
+        let mut a = Queue::new(5);
+        let mut b = Queue::new(5);
+
+        for i in 1..=5u32 {
+            let ret = a.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for _ in 0..3 {
+            a.dequeue();
+        }
+
+        let mut ret = a.enqueue(6u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = b.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = b.enqueue(5u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = b.enqueue(6u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_ne!(a.tail, b.tail);
+        assert!(a == b);
+    }
+
+    #[test]
+    fn queue_eq_detects_different_contents() {
+        let mut a = Queue::new(5);
+        let mut b = Queue::new(5);
+
+        let mut ret = a.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = b.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+
+        assert!(a != b);
+
+        ret = a.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+
+        assert!(a != b);
+
+        a.dequeue();
+        assert!(a == b);
+    }
+
+    #[test]
+    fn queue_eq_when_empty() {
+        let mut a = Queue::<u32>::new(5);
+        let b = Queue::<u32>::new(3);
+
+        assert!(a == b);
+
+        let ret = a.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+        a.dequeue();
+
+        assert!(a == b);
+    }
+
+    #[test]
+    fn queue_display_when_empty() {
+        let queue = Queue::<u32>::new(5);
+
+        assert_eq!(queue.to_string(), "[]");
+    }
+
+    #[test]
+    fn queue_display_single_element() {
+        let mut queue = Queue::new(5);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(queue.to_string(), "[1]");
+    }
+
+    #[test]
+    fn queue_display_front_to_back_after_wraparound() {
+        let mut queue = Queue::new(3);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut ret = queue.dequeue();
+        assert_eq!(ret, Some(1u32));
+
+        ret = queue.dequeue();
+        assert_eq!(ret, Some(2u32));
+
+        let mut enq = queue.enqueue(4u32);
+        assert_eq!(enq, Ok(()));
+
+        enq = queue.enqueue(5u32);
+        assert_eq!(enq, Ok(()));
+
+        assert_eq!(queue.to_string(), "[3, 4, 5]");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn queue_serde_round_trip_after_wraparound() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=5u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for _ in 0..3 {
+            queue.dequeue();
+        }
+
+        let mut ret = queue.enqueue(6u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(7u32);
+        assert_eq!(ret, Ok(()));
+
+        let json = serde_json::to_string(&queue).unwrap();
+        assert_eq!(json, r#"{"capacity":5,"elements":[4,5,6,7]}"#);
+
+        let mut restored: Queue<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.queue.capacity(), 5);
+        assert_eq!(restored.count, 4);
+        assert_eq!(restored.tail, 4);
+
+        for i in 4..=7u32 {
+            assert_eq!(restored.dequeue(), Some(i));
+        }
+
+        assert_eq!(restored.dequeue(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn queue_deserialize_more_elements_than_capacity_errors() {
+        let json = r#"{"capacity":1,"elements":[1,2]}"#;
+
+        let ret = serde_json::from_str::<Queue<u32>>(json);
+        assert!(ret.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn queue_deserialize_absurd_capacity_errors() {
+        let json = r#"{"capacity":18446744073709551615,"elements":[]}"#;
+
+        let ret = serde_json::from_str::<Queue<u32>>(json);
+        assert!(ret.unwrap_err().to_string().contains("allocation failed"));
+    }
+
+    #[test]
+    fn queue_drain_yields_front_to_back_after_wraparound() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=5u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for _ in 0..3 {
+            queue.dequeue();
+        }
+
+        for i in 6..=8u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let drained: Vec<u32> = queue.drain().collect();
+        assert_eq!(drained, vec![4u32, 5u32, 6u32, 7u32, 8u32]);
+    }
+
+    #[test]
+    fn queue_drain_leaves_queue_empty() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.drain().count(), 3);
+        assert_eq!(queue.count, 0);
+        assert_eq!(queue.dequeue(), None);
+
+        for item in &queue.queue {
+            assert_eq!(item, &None);
+        }
+    }
+
+    #[test]
+    fn queue_drain_dropped_early_clears_remaining() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=4u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut drain = queue.drain();
+        assert_eq!(drain.next(), Some(1u32));
+        drop(drain);
+
+        assert_eq!(queue.count, 0);
+        assert_eq!(queue.dequeue(), None);
+
+        for item in &queue.queue {
+            assert_eq!(item, &None);
+        }
+
+        let ret = queue.enqueue(9u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.dequeue(), Some(9u32));
+    }
+
+    #[test]
+    fn queue_holds_non_clone_type() {
+        #[derive(Debug, PartialEq)]
+        struct NoClone(u32);
+
+        let mut queue = Queue::new(2);
+
+        let mut ret = queue.enqueue(NoClone(1));
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(NoClone(2));
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(NoClone(3));
+        assert_eq!(ret, Err(QueueFullError));
+
+        assert_eq!(queue.peek(), Some(&NoClone(1)));
+        assert_eq!(queue.dequeue(), Some(NoClone(1)));
+
+        ret = queue.enqueue(NoClone(3));
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(queue.dequeue(), Some(NoClone(2)));
+        assert_eq!(queue.dequeue(), Some(NoClone(3)));
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn queue_holds_boxed_closures() {
+        let mut queue: Queue<Box<dyn FnMut() -> u32>> = Queue::new(2);
+
+        let mut counter = 0;
+        assert!(queue.enqueue(Box::new(move || { counter += 1; counter })).is_ok());
+        assert!(queue.enqueue(Box::new(|| 42)).is_ok());
+
+        let mut first = queue.dequeue().unwrap();
+        assert_eq!(first(), 1);
+        assert_eq!(first(), 2);
+
+        let mut second = queue.dequeue().unwrap();
+        assert_eq!(second(), 42);
+        assert!(queue.dequeue().is_none());
+    }
+
+    #[test]
+    fn queue_len_tracks_enqueue_and_dequeue() {
+        let mut queue = Queue::new(5);
+        assert_eq!(queue.len(), 0);
+
+        let mut ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.len(), 1);
+
+        ret = queue.enqueue(2u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.len(), 2);
+
+        queue.dequeue();
+        assert_eq!(queue.len(), 1);
+
+        queue.dequeue();
+        queue.dequeue();
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    fn queue_contains_after_wraparound() {
+        let mut queue = Queue::new(3);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        let ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        assert!(queue.contains(&3u32));
+        assert!(queue.contains(&4u32));
+        assert!(!queue.contains(&1u32));
+        assert!(!queue.contains(&2u32));
+    }
+
+    #[test]
+    fn queue_contains_when_empty() {
+        let queue = Queue::<u32>::new(3);
+
+        assert!(!queue.contains(&0u32));
+    }
+
+    #[test]
+    fn queue_enqueue_overwrite_evicts_oldest() {
+        let mut queue = Queue::new(5);
+        let mut evicted = Vec::new();
+
+        for i in 1..=7u32 {
+            if let Some(val) = queue.enqueue_overwrite(i) {
+                evicted.push(val);
+            }
+        }
+
+        assert_eq!(evicted, vec![1u32, 2u32]);
+        assert_eq!(queue.count, 5);
+
+        for i in 3..=7u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn queue_enqueue_overwrite_with_room_returns_none() {
+        let mut queue = Queue::new(5);
+
         let mut ret = queue.enqueue(1u32);
         assert_eq!(ret, Ok(()));
 
-        ret = queue.enqueue(2u32);
-        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.enqueue_overwrite(2u32), None);
+        assert_eq!(queue.count, 2);
+
+        ret = queue.enqueue(3u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(queue.dequeue(), Some(1u32));
+        assert_eq!(queue.dequeue(), Some(2u32));
+        assert_eq!(queue.dequeue(), Some(3u32));
+    }
+
+    #[test]
+    fn queue_enqueue_still_errors_after_overwrite() {
+        let mut queue = Queue::new(2);
+
+        assert_eq!(queue.enqueue_overwrite(1u32), None);
+        assert_eq!(queue.enqueue_overwrite(2u32), None);
+        assert_eq!(queue.enqueue_overwrite(3u32), Some(1u32));
+
+        let ret = queue.enqueue(4u32);
+        assert_eq!(ret, Err(QueueFullError));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn queue_full_error_propagates_as_boxed_error() {
+        fn overflow() -> Result<(), Box<dyn std::error::Error>> {
+            let mut queue = Queue::new(1);
+
+            queue.enqueue(1u32)?;
+            queue.enqueue(2u32)?;
+
+            Ok(())
+        }
+
+        let err = overflow().unwrap_err();
+        assert_eq!(err.to_string(), "queue is full");
+        assert!(err.downcast_ref::<QueueFullError>().is_some());
+    }
+
+    #[test]
+    fn queue_enqueue_batch_that_fits() {
+        let mut queue = Queue::new(5);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        let ret = queue.enqueue_batch(vec![2u32, 3u32, 4u32, 5u32]);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.count, 5);
+
+        for i in 1..=5u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+    }
+
+    #[test]
+    fn queue_enqueue_batch_overflow_returns_leftovers() {
+        let mut queue = Queue::new(4);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        let ret = queue.enqueue_batch(vec![4u32, 5u32, 6u32, 7u32, 8u32]);
+        assert_eq!(ret, Err(vec![7u32, 8u32]));
+        assert_eq!(queue.count, 4);
+
+        for i in 3..=6u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn queue_enqueue_batch_empty() {
+        let mut queue = Queue::<u32>::new(2);
+
+        let ret = queue.enqueue_batch(Vec::new());
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.count, 0);
+
+        queue.enqueue_batch(vec![1u32, 2u32]).unwrap();
+
+        let ret = queue.enqueue_batch(Vec::new());
+        assert_eq!(ret, Ok(()));
+    }
+
+    #[test]
+    fn queue_reset_with_larger_capacity() {
+        let mut queue = Queue::new(3);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+
+        queue.reset_with_capacity(6);
+        assert_eq!(queue.count, 0);
+        assert_eq!(queue.tail, 0);
+        assert_eq!(queue.queue.capacity(), 6);
+        assert_eq!(queue.queue.len(), 6);
+        assert_eq!(queue.dequeue(), None);
+
+        for i in 1..=6u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let ret = queue.enqueue(7u32);
+        assert_eq!(ret, Err(QueueFullError));
+
+        for i in 1..=6u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+    }
+
+    #[test]
+    fn queue_reset_with_smaller_capacity() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=5u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.reset_with_capacity(2);
+        assert_eq!(queue.queue.capacity(), 2);
+        assert_eq!(queue.queue.len(), 2);
+
+        for item in &queue.queue {
+            assert_eq!(item, &None);
+        }
+
+        let mut ret = queue.enqueue(6u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(7u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(8u32);
+        assert_eq!(ret, Err(QueueFullError));
+
+        assert_eq!(queue.dequeue(), Some(6u32));
+
+        ret = queue.enqueue(8u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(queue.dequeue(), Some(7u32));
+        assert_eq!(queue.dequeue(), Some(8u32));
+    }
+
+    #[test]
+    fn queue_retain_after_wraparound() {
+        let mut queue = Queue::new(5);
+
+        for i in 1..=5u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        for i in 6..=7u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert!(queue.is_full());
+
+        queue.retain(|x| x % 2 == 0);
+
+        assert_eq!(queue.len(), 2);
+        assert!(!queue.is_full());
+        assert_eq!(queue.queue.iter().filter(|x| x.is_some()).count(), 2);
+
+        let ret = queue.enqueue(8u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(queue.dequeue(), Some(4u32));
+        assert_eq!(queue.dequeue(), Some(6u32));
+        assert_eq!(queue.dequeue(), Some(8u32));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn queue_retain_all_and_none() {
+        let mut queue = Queue::new(3);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.retain(|_| true);
+        assert!(queue.is_full());
+        assert_eq!(queue.to_string(), "[1, 2, 3]");
+
+        queue.retain(|_| false);
+        assert!(queue.is_empty());
+
+        for item in &queue.queue {
+            assert_eq!(item, &None);
+        }
+
+        let ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.dequeue(), Some(4u32));
+    }
+
+    #[test]
+    fn queue_retain_when_empty() {
+        let mut queue = Queue::<u32>::new(3);
+
+        queue.retain(|_| false);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn queue_to_vec_matches_dequeue_order_after_wraparound() {
+        let mut queue = Queue::new(4);
+
+        for i in 1..=4u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        for i in 5..=6u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let v = queue.to_vec();
+        assert_eq!(v, vec![3u32, 4u32, 5u32, 6u32]);
+        assert_eq!(queue.len(), 4);
+
+        assert_eq!(queue.clone().into_vec(), v);
+
+        for val in v {
+            assert_eq!(queue.dequeue(), Some(val));
+        }
+    }
+
+    #[test]
+    fn queue_into_vec_when_empty() {
+        let queue = Queue::<u32>::new(4);
+        assert_eq!(queue.to_vec(), Vec::<u32>::new());
+        assert_eq!(queue.into_vec(), Vec::<u32>::new());
+
+        let queue = Queue::<u32>::new(0);
+        assert_eq!(queue.into_vec(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn queue_into_vec_moves_non_clone_values() {
+        #[derive(Debug, PartialEq)]
+        struct NoClone(u32);
+
+        let mut queue = Queue::new(2);
+
+        let mut ret = queue.enqueue(NoClone(1));
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(NoClone(2));
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(queue.into_vec(), vec![NoClone(1), NoClone(2)]);
+    }
+
+    #[test]
+    fn queue_default_is_empty_and_full() {
+        let mut queue = Queue::<u32>::default();
+
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.capacity(), 0);
+        assert!(queue.is_full());
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Err(QueueFullError));
+    }
+
+    #[test]
+    fn queue_enqueue_checked_reports_remaining() {
+        let mut queue = Queue::new(3);
+
+        assert_eq!(queue.enqueue_checked(1u32), Ok(2));
+        assert_eq!(queue.enqueue_checked(2u32), Ok(1));
+        assert_eq!(queue.enqueue_checked(3u32), Ok(0));
+
+        assert_eq!(queue.dequeue(), Some(1u32));
+
+        // wraps around into the freed slot
+        assert_eq!(queue.enqueue_checked(4u32), Ok(0));
+        assert_eq!(queue.count, 3);
+    }
+
+    #[test]
+    fn queue_enqueue_checked_when_full_returns_queuefullerror() {
+        let mut queue = Queue::new(1);
+
+        assert_eq!(queue.enqueue_checked(1u32), Ok(0));
+        assert_eq!(queue.enqueue_checked(2u32), Err(QueueFullError));
+        assert_eq!(queue.count, 1);
+        assert_eq!(queue.dequeue(), Some(1u32));
+    }
+
+    #[test]
+    fn queue_remaining_plus_len_is_capacity_after_wraparound() {
+        let mut queue = Queue::new(3);
+        assert_eq!(queue.remaining(), 3);
+
+        for i in 0..3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(queue.remaining() + queue.len(), queue.capacity());
+        }
+
+        assert_eq!(queue.remaining(), 0);
+
+        // cycle values through so the tail wraps around several times
+        for i in 3..10u32 {
+            assert!(queue.dequeue().is_some());
+            assert_eq!(queue.remaining(), 1);
+
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(queue.remaining() + queue.len(), queue.capacity());
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+        assert_eq!(queue.remaining(), 2);
+    }
+
+    #[test]
+    fn queue_append_within_capacity_empties_other() {
+        let mut queue = Queue::new(5);
+        let mut other = Queue::new(3);
+
+        for i in 1..=2u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for i in 3..=5u32 {
+            let ret = other.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.append(&mut other), Ok(()));
+        assert!(other.is_empty());
+        assert_eq!(other.capacity(), 3);
+        assert_eq!(queue.count, 5);
+
+        for i in 1..=5u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+    }
+
+    #[test]
+    fn queue_append_overflow_leaves_rest_in_other() {
+        let mut queue = Queue::new(3);
+        let mut other = Queue::new(4);
+
+        for i in 0..=2u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // wrap the tail of self around
+        queue.dequeue();
+
+        for i in 3..=6u32 {
+            let ret = other.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.append(&mut other), Err(QueueFullError));
+        assert!(queue.is_full());
+        assert_eq!(other.count, 3);
+
+        for i in 1..=3u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+
+        for i in 4..=6u32 {
+            assert_eq!(other.dequeue(), Some(i));
+        }
+    }
+
+    #[test]
+    fn queue_append_keeps_every_value() {
+        let mut queue = Queue::<u32>::new(0);
+        let mut other = Queue::new(2);
+
+        for i in 1..=2u32 {
+            let ret = other.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.append(&mut other), Err(QueueFullError));
+        assert_eq!(other.to_vec(), vec![1u32, 2u32]);
+
+        let mut queue = Queue::unbounded();
+        let ret = queue.enqueue(0u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(queue.append(&mut other), Ok(()));
+        assert!(other.is_empty());
+        assert_eq!(queue.to_vec(), vec![0u32, 1u32, 2u32]);
+    }
+
+    #[test]
+    fn queue_append_empty_other_when_full() {
+        let mut queue = Queue::new(1);
+        let mut other = Queue::<u32>::new(1);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(queue.append(&mut other), Ok(()));
+        assert_eq!(queue.count, 1);
+    }
+
+    #[test]
+    fn queue_unbounded_enqueue_past_capacity() {
+        let mut queue = Queue::unbounded();
+        assert_eq!(queue.capacity(), 0);
+        assert_eq!(queue.peek(), None);
+
+        for i in 0..100u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.len(), 100);
+        assert!(queue.capacity() >= 100);
+
+        for i in 0..100u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn queue_unbounded_grows_across_wraparound() {
+        let mut queue = Queue::unbounded();
+
+        for i in 0..4u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let capacity = queue.capacity();
+
+        queue.dequeue();
+        queue.dequeue();
+
+        // refill so the tail wraps around behind the head
+        for i in 4..6u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // at capacity, but an unbounded queue is never full
+        assert_eq!(queue.len(), queue.capacity());
+        assert!(!queue.is_full());
+        assert_ne!(queue.tail, 0);
+
+        for i in 6..9u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert!(queue.capacity() > capacity);
+        assert_eq!(queue.peek(), Some(&2u32));
+
+        for i in 2..9u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn queue_unbounded_batch_and_append_take_everything() {
+        let mut queue = Queue::unbounded();
+        let mut other = Queue::new(3);
+
+        let ret = queue.enqueue_batch(vec![1u32, 2u32, 3u32]);
+        assert_eq!(ret, Ok(()));
+
+        for i in 4..=6u32 {
+            let ret = other.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.append(&mut other), Ok(()));
+        assert!(other.is_empty());
+        assert_eq!(queue.enqueue_overwrite(7u32), None);
+
+        for i in 1..=7u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+    }
+
+    #[test]
+    fn queue_new_is_still_bounded() {
+        let mut queue = Queue::new(1);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        let ret = queue.enqueue(2u32);
+        assert_eq!(ret, Err(QueueFullError));
+        assert_eq!(queue.capacity(), 1);
+    }
+
+    #[test]
+    fn queue_shrink_to_floor_after_drain() {
+        let mut queue = Queue::new(8);
+
+        for i in 0..8u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for _ in 0..7 {
+            queue.dequeue();
+        }
+
+        queue.shrink_to(3);
+        assert_eq!(queue.capacity(), 3);
+        assert_eq!(queue.queue.len(), 3);
+        assert_eq!(queue.len(), 1);
+
+        let mut ret = queue.enqueue(8u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(9u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = queue.enqueue(10u32);
+        assert_eq!(ret, Err(QueueFullError));
+
+        for i in 7..=9u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+    }
+
+    #[test]
+    fn queue_shrink_to_keeps_elements_after_wraparound() {
+        let mut queue = Queue::new(6);
+
+        for i in 0..6u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        for _ in 0..4 {
+            queue.dequeue();
+        }
+
+        for i in 6..8u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // floor below len shrinks to len
+        queue.shrink_to(1);
+        assert_eq!(queue.capacity(), 4);
+        assert!(queue.is_full());
+
+        for i in 4..8u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+    }
+
+    #[test]
+    fn queue_shrink_to_larger_is_noop() {
+        let mut queue = Queue::new(4);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+
+        queue.shrink_to(4);
+        assert_eq!(queue.capacity(), 4);
+
+        queue.shrink_to(10);
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.dequeue(), Some(1u32));
+
+        queue.shrink_to(0);
+        assert_eq!(queue.capacity(), 0);
+        assert_eq!(queue.enqueue(2u32), Err(QueueFullError));
+    }
+
+    #[test]
+    fn queue_zero_capacity_never_panics() {
+        let mut queue = Queue::<u32>::new(0);
+
+        assert_eq!(queue.enqueue(1u32), Err(QueueFullError));
+        assert_eq!(queue.enqueue_checked(1u32), Err(QueueFullError));
+        assert_eq!(queue.enqueue_batch(vec![1u32, 2u32]), Err(vec![1u32, 2u32]));
+        assert_eq!(queue.enqueue_overwrite(1u32), Some(1u32));
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.peek(), None);
+
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.capacity(), 0);
+        assert_eq!(queue.remaining(), 0);
+        assert!(queue.is_empty());
+        assert!(queue.is_full());
+        assert!(!queue.contains(&1u32));
+
+        let mut other = Queue::new(1);
+        assert_eq!(other.enqueue(1u32), Ok(()));
+        assert_eq!(queue.append(&mut other), Err(QueueFullError));
+        assert_eq!(other.len(), 1);
+
+        let mut empty = Queue::<u32>::new(0);
+        assert_eq!(queue.append(&mut empty), Ok(()));
+
+        queue.retain(|_| true);
+        queue.shrink_to(0);
+        queue.clear();
+        assert_eq!(queue.drain().next(), None);
+        assert_eq!(queue.to_vec(), Vec::<u32>::new());
+        assert_eq!(format!("{:?}", queue), "Queue { size: 0, capacity: 0, elements: [] }");
+        assert_eq!(format!("{}", queue), "[]");
+        assert!(queue == Queue::new(0));
+        assert_eq!(queue.clone().into_vec(), Vec::<u32>::new());
+
+        queue.reset_with_capacity(0);
+        assert_eq!(queue.enqueue(1u32), Err(QueueFullError));
+    }
+
+    #[test]
+    fn queue_equal_queues_hash_equally_after_wraparound() {
+        use std::collections::HashSet;
+
+        let mut a = Queue::new(3);
+        let mut b = Queue::new(5);
+
+        for i in 1..=3u32 {
+            let ret = a.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        a.dequeue();
+
+        let ret = a.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+
+        for i in 2..=4u32 {
+            let ret = b.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        set.insert(b);
+        assert_eq!(set.len(), 1);
+
+        let mut c = Queue::new(3);
+        c.enqueue_batch(vec![4u32, 3u32, 2u32]).unwrap();
+
+        set.insert(c);
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "internals")]
+    fn queue_debug_head_and_tail_after_wraparound() {
+        let mut queue = Queue::new(3);
+        assert_eq!((queue.debug_head(), queue.debug_tail()), (0, 0));
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // head and tail share a slot when full
+        assert_eq!((queue.debug_head(), queue.debug_tail()), (0, 0));
+
+        queue.dequeue();
+        queue.dequeue();
+        assert_eq!((queue.debug_head(), queue.debug_tail()), (2, 0));
+
+        let ret = queue.enqueue(4u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!((queue.debug_head(), queue.debug_tail()), (2, 1));
+
+        queue.dequeue();
+        queue.dequeue();
+        assert_eq!((queue.debug_head(), queue.debug_tail()), (1, 1));
+    }
+
+    #[test]
+    fn queue_on_full_fires_once_per_fill() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let fills = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&fills);
+
+        let mut queue = Queue::new(3);
+        queue.on_full(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        for i in 0..3u32 {
+            assert_eq!(fills.load(Ordering::SeqCst), 0);
+
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(fills.load(Ordering::SeqCst), 1);
+
+        // failed enqueues and overwrites while full are not transitions
+        assert_eq!(queue.enqueue(3u32), Err(QueueFullError));
+        assert_eq!(queue.enqueue_overwrite(3u32), Some(0u32));
+        assert_eq!(fills.load(Ordering::SeqCst), 1);
+
+        for cycle in 2..=4 {
+            queue.dequeue();
+
+            let ret = queue.enqueue(cycle);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(fills.load(Ordering::SeqCst), cycle as usize);
+        }
+
+        // draining fully then refilling fires again
+        while queue.dequeue().is_some() {}
+
+        let ret = queue.enqueue_batch(vec![5u32, 6u32, 7u32]);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(fills.load(Ordering::SeqCst), 5);
+
+        queue.dequeue();
+        assert_eq!(queue.enqueue_overwrite(8u32), None);
+        assert_eq!(fills.load(Ordering::SeqCst), 6);
+    }
+
+    #[test]
+    fn queue_on_full_not_cloned() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let fills = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&fills);
+
+        let mut queue = Queue::new(1);
+        queue.on_full(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let mut copy = queue.clone();
+
+        let ret = copy.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(fills.load(Ordering::SeqCst), 0);
+
+        let ret = queue.enqueue(1u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(fills.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn queue_dequeue_n_across_wraparound() {
+        let mut queue = Queue::new(4);
+
+        for i in 1..=4u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        for i in 5..=6u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // logical [3, 4, 5, 6] stored as [5, 6, 3, 4]
+        assert_eq!(queue.dequeue_n(3), vec![3u32, 4u32, 5u32]);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.peek(), Some(&6u32));
+
+        let empty = queue.queue.iter().filter(|slot| slot.is_none()).count();
+        assert_eq!(empty, 3);
+
+        let ret = queue.enqueue(7u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(queue.to_vec(), vec![6u32, 7u32]);
+    }
+
+    #[test]
+    fn queue_dequeue_n_more_than_len_returns_all() {
+        let mut queue = Queue::new(3);
+
+        for i in 1..=2u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.dequeue_n(5), vec![1u32, 2u32]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.dequeue_n(1), Vec::<u32>::new());
+        assert_eq!(queue.dequeue_n(0), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn queue_iter_mut_updates_in_place_after_wraparound() {
+        let mut queue = Queue::new(4);
+
+        for i in 1..=4u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        for i in 5..=6u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // logical [3, 4, 5, 6] stored as [5, 6, 3, 4]
+        let mut order = Vec::new();
+
+        for (i, val) in queue.iter_mut().enumerate() {
+            order.push(*val);
+            *val += 10 * (i as u32 + 1);
+        }
+
+        assert_eq!(order, vec![3u32, 4u32, 5u32, 6u32]);
+
+        for expected in [13u32, 24u32, 35u32, 46u32] {
+            assert_eq!(queue.dequeue(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn queue_iter_mut_skips_empty_slots() {
+        let mut queue = Queue::new(5);
+        assert_eq!(queue.iter_mut().next(), None);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        assert_eq!(queue.iter_mut().count(), 2);
+
+        let mut zero = Queue::<u32>::new(0);
+        assert_eq!(zero.iter_mut().next(), None);
+    }
+
+    #[test]
+    fn queue_from_array_is_full() {
+        let mut queue = Queue::from([1u32, 2u32, 3u32]);
+
+        assert_eq!(queue.capacity(), 3);
+        assert!(queue.is_full());
+        assert_eq!(queue.enqueue(4u32), Err(QueueFullError));
+
+        for i in 1..=3u32 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+
+        assert_eq!(queue.dequeue(), None);
+
+        let mut queue = Queue::from([1u32]);
+        assert_eq!(queue.capacity(), 1);
+        assert_eq!(queue.dequeue(), Some(1u32));
+    }
+
+    #[test]
+    fn queue_from_empty_array() {
+        let mut queue = Queue::<u32>::from([]);
+
+        assert!(queue.is_empty());
+        assert!(queue.is_full());
+        assert_eq!(queue.enqueue(1u32), Err(QueueFullError));
+    }
+
+    #[test]
+    fn queue_saturating_enqueue_drops_new_value_when_full() {
+        let mut queue = Queue::new(2);
+
+        assert!(queue.saturating_enqueue(1u32));
+        assert!(queue.saturating_enqueue(2u32));
+        assert!(!queue.saturating_enqueue(3u32));
+        assert!(!queue.saturating_enqueue(4u32));
+        assert_eq!(queue.len(), 2);
+
+        assert_eq!(queue.dequeue(), Some(1u32));
+        assert!(queue.saturating_enqueue(5u32));
+
+        assert_eq!(queue.dequeue(), Some(2u32));
+        assert_eq!(queue.dequeue(), Some(5u32));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn queue_saturating_enqueue_zero_capacity_and_unbounded() {
+        let mut queue = Queue::new(0);
+        assert!(!queue.saturating_enqueue(1u32));
+
+        let mut queue = Queue::unbounded();
+
+        for i in 0..10u32 {
+            assert!(queue.saturating_enqueue(i));
+        }
+
+        assert_eq!(queue.len(), 10);
+    }
+
+    #[test]
+    fn queue_drain_while_prefix_after_wraparound() {
+        let mut queue = Queue::new(4);
+
+        for i in 1..=4u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        for i in [5u32, 1u32] {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // logical [3, 4, 5, 1] stored as [5, 1, 3, 4]
+        assert_eq!(queue.drain_while(|x| *x < 5), vec![3u32, 4u32]);
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.peek(), Some(&5u32));
+        assert_eq!(queue.to_vec(), vec![5u32, 1u32]);
+    }
+
+    #[test]
+    fn queue_drain_while_nothing_matches() {
+        let mut queue = Queue::new(3);
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.drain_while(|x| *x > 1), Vec::<u32>::new());
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(), Some(&1u32));
+    }
 
-        ret = queue.enqueue(3u32);
-        assert_eq!(ret, Ok(()));
+    #[test]
+    fn queue_drain_while_everything_matches() {
+        let mut queue = Queue::new(3);
 
-        ret = queue.enqueue(4u32);
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.drain_while(|_| true), vec![1u32, 2u32, 3u32]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.drain_while(|_| true), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn queue_ord_is_lexicographic_after_wraparound() {
+        let mut a = Queue::new(3);
+
+        for i in 1..=3u32 {
+            let ret = a.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        a.dequeue();
+
+        let ret = a.enqueue(4u32);
         assert_eq!(ret, Ok(()));
 
-        let mut ret = queue.dequeue();
-        assert_eq!(ret, Some(1u32));
+        // logical [2, 3, 4]
+        assert_eq!(a.cmp(&Queue::from([2u32, 3u32, 4u32])), Ordering::Equal);
+        assert!(a < Queue::from([2u32, 5u32, 0u32]));
+        assert!(a > Queue::from([2u32, 3u32]));
+        assert!(Queue::<u32>::new(3) < a);
+    }
 
-        ret = queue.dequeue();
-        assert_eq!(ret, Some(2u32));
+    #[test]
+    fn queue_rotate_when_full() {
+        let mut queue = Queue::from([1u32, 2u32, 3u32, 4u32]);
 
-        ret = queue.dequeue();
-        assert_eq!(ret, Some(3u32));
+        queue.rotate();
+        assert!(queue.is_full());
+        assert_eq!(queue.to_vec(), vec![2u32, 3u32, 4u32, 1u32]);
 
-        ret = queue.dequeue();
-        assert_eq!(ret, Some(4u32));
+        queue.rotate_n(2);
+        assert_eq!(queue.to_vec(), vec![4u32, 1u32, 2u32, 3u32]);
 
-        assert_eq!(queue.count, 0);
+        queue.rotate_n(8);
+        assert_eq!(queue.to_vec(), vec![4u32, 1u32, 2u32, 3u32]);
+
+        for i in [4u32, 1u32, 2u32, 3u32] {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+
+        assert_eq!(queue.dequeue(), None);
     }
 
     #[test]
-    fn queue_enqueue_returns_queuefullerror_when_already_full() {
+    fn queue_rotate_with_room_after_wraparound() {
         let mut queue = Queue::new(5);
 
-        let mut ret = queue.enqueue(1u32);
-        assert_eq!(ret, Ok(()));
+        for i in 1..=5u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
 
-        ret = queue.enqueue(2u32);
-        assert_eq!(ret, Ok(()));
+        queue.dequeue();
+        queue.dequeue();
 
-        ret = queue.enqueue(3u32);
-        assert_eq!(ret, Ok(()));
+        // logical [3, 4, 5] with the back at the end of the buffer
+        queue.rotate_n(4);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.to_vec(), vec![4u32, 5u32, 3u32]);
+        assert_eq!(queue.peek_back(), Some(&3u32));
 
-        ret = queue.enqueue(4u32);
+        let ret = queue.enqueue(6u32);
         assert_eq!(ret, Ok(()));
 
-        ret = queue.enqueue(5u32);
-        assert_eq!(ret, Ok(()));
+        for i in [4u32, 5u32, 3u32, 6u32] {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+    }
 
-        ret = queue.enqueue(6u32);
-        assert_eq!(ret, Err(QueueFullError));
+    #[test]
+    fn queue_rotate_when_empty() {
+        let mut queue = Queue::<u32>::new(3);
+        queue.rotate();
+        queue.rotate_n(10);
+        assert!(queue.is_empty());
+
+        let mut queue = Queue::<u32>::new(0);
+        queue.rotate();
+        assert!(queue.is_empty());
     }
 
     #[test]
-    fn queue_peek_returns_ref_to_head() {
-        let mut queue = Queue::new(5);
+    #[cfg(feature = "std")]
+    fn queue_io_round_trip_after_wraparound() {
+        use std::io::{Read, Write};
 
-        let mut ret = queue.enqueue(1u32);
-        assert_eq!(ret, Ok(()));
+        let mut queue = Queue::new(4);
+        let mut buf = [0u8; 8];
 
-        let mut peek = queue.peek();
-        let mut head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(Some(peek), queue.queue.get(head));
+        assert_eq!(queue.write(b"abc").unwrap(), 3);
+        assert_eq!(queue.read(&mut buf[..2]).unwrap(), 2);
+        assert_eq!(&buf[..2], b"ab");
 
-        ret = queue.enqueue(2u32);
-        assert_eq!(ret, Ok(()));
+        // wraps around the end of the buffer
+        queue.write_all(b"def").unwrap();
+        assert!(queue.flush().is_ok());
 
-        peek = queue.peek();
-        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(Some(peek), queue.queue.get(head));
+        assert_eq!(queue.read(&mut buf).unwrap(), 4);
+        assert_eq!(&buf[..4], b"cdef");
+        assert_eq!(queue.read(&mut buf).unwrap(), 0);
 
-        ret = queue.enqueue(3u32);
+        let mut out = Vec::new();
+        queue.write_all(b"xyz").unwrap();
+        assert_eq!(queue.read_to_end(&mut out).unwrap(), 3);
+        assert_eq!(out, b"xyz".to_vec());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn queue_io_write_past_capacity_is_short() {
+        use std::io::{ErrorKind, Read, Write};
+
+        let mut queue = Queue::new(3);
+
+        assert_eq!(queue.write(b"abcde").unwrap(), 3);
+        assert_eq!(queue.write(b"f").unwrap(), 0);
+
+        let mut buf = [0u8; 5];
+        assert_eq!(queue.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf[..3], b"abc");
+
+        let err = queue.write_all(b"abcde").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+        assert_eq!(queue.to_vec(), b"abc".to_vec());
+    }
+
+    #[test]
+    fn array_queue_enqueue_to_capacity_then_full() {
+        let mut queue = ArrayQueue::<u32, 3>::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.capacity(), 3);
+
+        for val in [542u32, 543u32, 544u32] {
+            assert_eq!(queue.enqueue(val), Ok(()));
+        }
+
+        assert!(queue.is_full());
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.enqueue(545u32), Err(QueueFullError));
+        assert_eq!(queue.peek(), Some(&542u32));
+    }
+
+    #[test]
+    fn array_queue_wraps_around() {
+        let mut queue = ArrayQueue::<u32, 3>::default();
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.peek(), None);
+
+        // cycle through the array several times so head and tail wrap
+        for i in 0..10u32 {
+            assert_eq!(queue.enqueue(i), Ok(()));
+            assert_eq!(queue.enqueue(i + 100), Ok(()));
+            assert_eq!(queue.dequeue(), Some(i));
+            assert_eq!(queue.peek(), Some(&(i + 100)));
+            assert_eq!(queue.dequeue(), Some(i + 100));
+        }
+
+        assert!(queue.is_empty());
+
+        for val in [1u32, 2u32, 3u32] {
+            assert_eq!(queue.enqueue(val), Ok(()));
+        }
+
+        assert_eq!(queue.dequeue(), Some(1u32));
+        assert_eq!(queue.enqueue(4u32), Ok(()));
+        assert_eq!(queue.enqueue(5u32), Err(QueueFullError));
+
+        assert_eq!(queue.dequeue(), Some(2u32));
+        assert_eq!(queue.dequeue(), Some(3u32));
+        assert_eq!(queue.dequeue(), Some(4u32));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn array_queue_zero_capacity() {
+        let mut queue = ArrayQueue::<u32, 0>::new();
+
+        assert!(queue.is_full());
+        assert_eq!(queue.enqueue(542u32), Err(QueueFullError));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn array_queue_drops_remaining_elements() {
+        use alloc::rc::Rc;
+
+        let tracker = Rc::new(());
+
+        let mut queue = ArrayQueue::<Rc<()>, 4>::new();
+
+        // wrap the live elements around the end of the array
+        for _ in 0..3 {
+            assert!(queue.enqueue(Rc::clone(&tracker)).is_ok());
+        }
+
+        drop(queue.dequeue());
+        drop(queue.dequeue());
+
+        for _ in 0..2 {
+            assert!(queue.enqueue(Rc::clone(&tracker)).is_ok());
+        }
+
+        assert_eq!(Rc::strong_count(&tracker), 4);
+
+        drop(queue);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn queue_iter_matches_dequeue_order() {
+        let mut queue = Queue::<u32>::new(4);
+
+        for val in [1u32, 2u32, 3u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        // live values now wrap around the end of the inner vec
+        for val in [4u32, 5u32, 6u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.tail, 2);
+
+        let forward: Vec<u32> = queue.iter().copied().collect();
+        let mut backward: Vec<u32> = queue.iter().rev().copied().collect();
+
+        let mut dequeued = Vec::new();
+        while let Some(val) = queue.dequeue() {
+            dequeued.push(val);
+        }
+
+        assert_eq!(forward, vec![3u32, 4u32, 5u32, 6u32]);
+        assert_eq!(forward, dequeued);
+
+        backward.reverse();
+        assert_eq!(backward, dequeued);
+    }
+
+    #[test]
+    fn queue_iter_next_back_meets_next() {
+        let mut queue = Queue::<u32>::new(3);
+
+        assert_eq!(queue.iter().next_back(), None);
+
+        for val in [1u32, 2u32, 3u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        let ret = queue.enqueue(4u32);
         assert_eq!(ret, Ok(()));
 
-        peek = queue.peek();
-        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(Some(peek), queue.queue.get(head));
+        let mut iter = queue.iter();
 
-        ret = queue.enqueue(4u32);
+        assert_eq!(iter.next_back(), Some(&4u32));
+        assert_eq!(iter.next(), Some(&2u32));
+        assert_eq!(iter.next_back(), Some(&3u32));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn queue_try_new_with_absurd_size_is_err() {
+        // the size overflows before any allocation is attempted
+        let ret = Queue::<u32>::try_new(isize::MAX as usize);
+        assert_eq!(ret.err(), Some(AllocError));
+
+        let ret = Queue::<u64>::try_new(usize::MAX);
+        assert_eq!(ret.err(), Some(AllocError));
+    }
+
+    #[test]
+    fn queue_try_new_creates_empty_queue() {
+        let mut queue = Queue::<u32>::try_new(3).unwrap();
+
+        assert!(queue.is_empty());
+        assert_eq!(queue.capacity(), 3);
+        assert_eq!(queue.queue.len(), 3);
+        assert!(queue.queue.iter().all(Option::is_none));
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.enqueue(545u32), Err(QueueFullError));
+        assert_eq!(queue.dequeue(), Some(542u32));
+    }
+
+    #[test]
+    fn queue_reserve_when_wrapped_keeps_order() {
+        let mut queue = Queue::<u32>::new(4);
+
+        for val in [1u32, 2u32, 3u32, 4u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        for val in [5u32, 6u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // full with the head in the middle of the inner vec
+        assert_eq!(queue.tail, 2);
+        assert_eq!(queue.enqueue(7u32), Err(QueueFullError));
+
+        queue.reserve(3);
+
+        assert_eq!(queue.capacity(), 7);
+        assert_eq!(queue.tail, 4);
+        assert_eq!(queue.queue.len(), 7);
+        assert_eq!(queue.queue[0], Some(3u32));
+
+        for val in [7u32, 8u32, 9u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.enqueue(10u32), Err(QueueFullError));
+        assert_eq!(queue.dequeue_n(7), vec![3u32, 4u32, 5u32, 6u32, 7u32, 8u32, 9u32]);
+    }
+
+    #[test]
+    fn queue_reserve_with_enough_room_is_noop() {
+        let mut queue = Queue::<u32>::new(4);
+
+        let ret = queue.enqueue(542u32);
         assert_eq!(ret, Ok(()));
 
-        peek = queue.peek();
-        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(Some(peek), queue.queue.get(head));
+        queue.reserve(3);
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.tail, 1);
 
-        let mut ret = queue.dequeue();
-        assert_eq!(ret, Some(1u32));
+        let mut queue = Queue::<u32>::new(0);
+        queue.reserve(2);
 
-        peek = queue.peek();
-        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(Some(peek), queue.queue.get(head));
+        assert_eq!(queue.capacity(), 2);
+        assert_eq!(queue.enqueue(542u32), Ok(()));
+        assert_eq!(queue.peek(), Some(&542u32));
+    }
 
-        ret = queue.dequeue();
-        assert_eq!(ret, Some(2u32));
+    fn formula_head<T>(queue: &Queue<T>) -> usize {
+        if queue.count == 0 {
+            queue.tail
+        } else {
+            (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity()
+        }
+    }
 
-        peek = queue.peek();
-        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(Some(peek), queue.queue.get(head));
+    #[test]
+    fn queue_head_tracks_formula_through_wraparound() {
+        let mut queue = Queue::<u32>::new(3);
 
-        ret = queue.dequeue();
-        assert_eq!(ret, Some(3u32));
+        for i in 0..20u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(queue.head, formula_head(&queue));
 
-        peek = queue.peek();
-        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(Some(peek), queue.queue.get(head));
+            if i % 3 != 0 {
+                queue.dequeue();
+            }
 
-        ret = queue.dequeue();
-        assert_eq!(ret, Some(4u32));
+            assert_eq!(queue.head, formula_head(&queue));
 
-        peek = queue.peek();
-        head = (queue.tail + queue.queue.capacity() - queue.count) % queue.queue.capacity();
-        assert_eq!(Some(peek), queue.queue.get(head));
+            if queue.is_full() {
+                queue.dequeue();
+                queue.dequeue();
+                assert_eq!(queue.head, formula_head(&queue));
+            }
+        }
+
+        while queue.dequeue().is_some() {
+            assert_eq!(queue.head, formula_head(&queue));
+        }
+
+        assert_eq!(queue.head, queue.tail);
+    }
+
+    #[test]
+    fn queue_head_tracks_formula_through_reshaping() {
+        let mut queue = Queue::<u32>::new(4);
+
+        for i in 0..6u32 {
+            queue.enqueue_overwrite(i);
+            assert_eq!(queue.head, formula_head(&queue));
+        }
+
+        queue.rotate_n(3);
+        assert_eq!(queue.head, formula_head(&queue));
+
+        queue.dequeue();
+        queue.rotate_n(2);
+        assert_eq!(queue.head, formula_head(&queue));
+
+        queue.retain(|x| x % 2 == 0);
+        assert_eq!(queue.head, formula_head(&queue));
+
+        let order = queue.to_vec();
+
+        queue.reserve(4);
+        assert_eq!(queue.head, 0);
+        assert_eq!(queue.head, formula_head(&queue));
+
+        queue.shrink_to(0);
+        assert_eq!(queue.head, formula_head(&queue));
+        assert_eq!(queue.to_vec(), order);
+
+        queue.clear();
+        assert_eq!(queue.head, formula_head(&queue));
+    }
+
+    #[test]
+    fn queue_utilization_tracks_len() {
+        let mut queue = Queue::<u32>::new(4);
+        assert_eq!(queue.utilization(), 0.0);
+
+        for val in [542u32, 543u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.utilization(), 0.5);
+
+        for val in [544u32, 545u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(queue.utilization(), 1.0);
+
+        queue.dequeue();
+        assert_eq!(queue.utilization(), 0.75);
+    }
+
+    #[test]
+    fn queue_utilization_zero_capacity_is_not_nan() {
+        let queue = Queue::<u32>::new(0);
+
+        assert!(!queue.utilization().is_nan());
+        assert_eq!(queue.utilization(), 1.0);
+    }
+
+    #[test]
+    fn queue_peek_at_across_wraparound() {
+        let mut queue = Queue::<u32>::new(4);
+
+        for val in [1u32, 2u32, 3u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        queue.dequeue();
+        queue.dequeue();
+
+        for val in [4u32, 5u32, 6u32] {
+            let ret = queue.enqueue(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // logical [3, 4, 5, 6] stored as [5, 6, 3, 4]
+        assert_eq!(queue.head, 2);
+
+        let peeked: Vec<u32> = (0..queue.len()).map(|i| *queue.peek_at(i).unwrap()).collect();
+        assert_eq!(queue.peek_at(queue.len()), None);
+
+        let mut dequeued = Vec::new();
+        while let Some(val) = queue.dequeue() {
+            dequeued.push(val);
+        }
+
+        assert_eq!(peeked, vec![3u32, 4u32, 5u32, 6u32]);
+        assert_eq!(peeked, dequeued);
+    }
+
+    #[test]
+    fn queue_peek_at_when_empty() {
+        let queue = Queue::<u32>::new(3);
+        assert_eq!(queue.peek_at(0), None);
+
+        let queue = Queue::<u32>::new(0);
+        assert_eq!(queue.peek_at(0), None);
+    }
+
+    #[test]
+    fn queue_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Queue<u32>>();
+        assert_send_sync::<Queue<alloc::string::String>>();
+    }
+
+    #[test]
+    fn queue_unbounded_is_never_full() {
+        let mut queue = Queue::<u32>::unbounded();
+
+        assert!(!queue.is_full());
+        assert!(!Collection::is_full(&queue));
+
+        for val in [542u32, 543u32, 544u32] {
+            assert!(queue.saturating_enqueue(val));
+            assert!(!queue.is_full());
+        }
+
+        let mut bounded = Queue::<u32>::new(1);
+
+        assert!(bounded.saturating_enqueue(542u32));
+        assert!(bounded.is_full());
+        assert!(Collection::is_full(&bounded));
+        assert_eq!(bounded.remaining(), 0);
+        assert!(!bounded.saturating_enqueue(543u32));
+    }
+
+    #[test]
+    fn queue_on_full_never_fires_when_unbounded() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let fills = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&fills);
+
+        let mut queue = Queue::unbounded();
+        queue.on_full(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // grows through capacities 1, 2, 4, 8 and 16
+        for i in 0..10u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(queue.enqueue_overwrite(i), None);
+        }
+
+        assert!(queue.capacity() >= 20);
+        assert_eq!(fills.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn queue_serde_round_trip_unbounded() {
+        let mut queue = Queue::unbounded();
+
+        for i in 1..=3u32 {
+            let ret = queue.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let capacity = queue.capacity();
+
+        let json = serde_json::to_string(&queue).unwrap();
+        assert!(json.ends_with(r#""elements":[1,2,3],"growable":true}"#));
+
+        let mut restored: Queue<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.capacity(), capacity);
+
+        for i in 4..=(capacity as u32 + 1) {
+            let ret = restored.enqueue(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert!(restored.capacity() > capacity);
+        assert!(!restored.is_full());
+
+        for i in 1..=(capacity as u32 + 1) {
+            assert_eq!(restored.dequeue(), Some(i));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn queue_serde_round_trip_empty_unbounded() {
+        let queue = Queue::<u32>::unbounded();
+
+        let json = serde_json::to_string(&queue).unwrap();
+        assert_eq!(json, r#"{"capacity":0,"elements":[],"growable":true}"#);
+
+        let mut restored: Queue<u32> = serde_json::from_str(&json).unwrap();
+
+        let ret = restored.enqueue(542u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(restored.dequeue(), Some(542u32));
     }
 }
@@ -0,0 +1,125 @@
+use std::time::{Duration, Instant};
+use crate::queue::Queue;
+
+/// A sliding window rate limiter that allows at most `max_events` within 
+/// any `window` of time. The times of the allowed events are kept in a 
+/// `Queue` with a capacity of `max_events`, oldest at the front.
+/// ```
+/// use rsds::rate::RateLimiter;
+/// use std::time::{Duration, Instant};
+/// 
+/// let mut limiter = RateLimiter::new(2, Duration::from_secs(1));
+/// let start = Instant::now();
+/// 
+/// assert!(limiter.try_acquire(start));
+/// assert!(limiter.try_acquire(start));
+/// assert!(!limiter.try_acquire(start));
+/// 
+/// // both events have left the window
+/// assert!(limiter.try_acquire(start + Duration::from_secs(2)));
+/// ```
+pub struct RateLimiter {
+    events: Queue<Instant>,
+    window: Duration,
+}
+
+impl RateLimiter {
+    /// Create a new `RateLimiter` allowing up to `max_events` within any 
+    /// `window` of time.
+    /// ```
+    /// use rsds::rate::RateLimiter;
+    /// use std::time::Duration;
+    /// 
+    /// // at most 10 events per second
+    /// let limiter = RateLimiter::new(10, Duration::from_secs(1));
+    /// ```
+    pub fn new(max_events: usize, window: Duration) -> Self {
+        RateLimiter {
+            events: Queue::new(max_events),
+            window,
+        }
+    }
+
+    /// Records an event at `now` and returns `true` if fewer than 
+    /// `max_events` events happened within the window ending at `now`. 
+    /// Otherwise the event is not recorded and `false` is returned. Events 
+    /// older than `now - window` are forgotten first. `now` should not go 
+    /// backwards between calls.
+    /// ```
+    /// use rsds::rate::RateLimiter;
+    /// use std::time::{Duration, Instant};
+    /// 
+    /// let mut limiter = RateLimiter::new(1, Duration::from_secs(1));
+    /// let start = Instant::now();
+    /// 
+    /// assert!(limiter.try_acquire(start));
+    /// assert!(!limiter.try_acquire(start + Duration::from_millis(500)));
+    /// ```
+    pub fn try_acquire(&mut self, now: Instant) -> bool {
+        while let Some(oldest) = self.events.peek() {
+            if now.saturating_duration_since(*oldest) <= self.window {
+                break;
+            }
+
+            self.events.dequeue();
+        }
+
+        self.events.enqueue(now).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod test_rate {
+    use crate::rate::*;
+
+    #[test]
+    fn rate_burst_over_limit_is_rejected() {
+        let mut limiter = RateLimiter::new(3, Duration::from_secs(1));
+        let start = Instant::now();
+
+        for i in 0..3u64 {
+            assert!(limiter.try_acquire(start + Duration::from_millis(i * 10)));
+        }
+
+        for i in 3..10u64 {
+            assert!(!limiter.try_acquire(start + Duration::from_millis(i * 10)));
+        }
+    }
+
+    #[test]
+    fn rate_events_under_limit_are_allowed() {
+        let mut limiter = RateLimiter::new(2, Duration::from_secs(1));
+        let start = Instant::now();
+
+        // one event every 600ms never has more than two in a window
+        for i in 0..10u64 {
+            assert!(limiter.try_acquire(start + Duration::from_millis(i * 600)));
+        }
+    }
+
+    #[test]
+    fn rate_window_slides() {
+        let mut limiter = RateLimiter::new(2, Duration::from_secs(1));
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire(start));
+        assert!(limiter.try_acquire(start + Duration::from_millis(500)));
+        assert!(!limiter.try_acquire(start + Duration::from_millis(900)));
+
+        // an event exactly one window old is still counted
+        assert!(!limiter.try_acquire(start + Duration::from_millis(1000)));
+
+        // the first event has left the window but the second has not
+        assert!(limiter.try_acquire(start + Duration::from_millis(1001)));
+        assert!(!limiter.try_acquire(start + Duration::from_millis(1200)));
+
+        assert!(limiter.try_acquire(start + Duration::from_millis(1501)));
+    }
+
+    #[test]
+    fn rate_zero_events_never_allows() {
+        let mut limiter = RateLimiter::new(0, Duration::from_secs(1));
+
+        assert!(!limiter.try_acquire(Instant::now()));
+    }
+}
@@ -0,0 +1,51 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::Error;
+
+use crate::collection::GrowthPolicy;
+
+/// The serialized form shared by the collections: the capacity and the 
+/// elements in the order they are added back when deserializing. The 
+/// optional fields are only written by the collections that use them, 
+/// and default when missing so older payloads still load.
+#[derive(serde::Deserialize)]
+pub(crate) struct Repr<T> {
+    pub(crate) capacity: usize,
+    pub(crate) elements: Vec<T>,
+    #[serde(default)]
+    pub(crate) growth: Option<GrowthPolicy>,
+    #[serde(default)]
+    pub(crate) growable: bool,
+}
+
+impl<T> Repr<T> {
+    /// Rebuilds a collection by allocating it with `try_new` and adding 
+    /// every element with `insert`. `name` is used in the error returned 
+    /// when there are more elements than capacity.
+    pub(crate) fn build<C, A, F, E>(
+        self,
+        name: &str,
+        try_new: impl FnOnce(usize) -> Result<C, A>,
+        mut insert: impl FnMut(&mut C, T) -> Result<(), F>,
+    ) -> Result<C, E>
+    where
+        A: fmt::Display,
+        F: fmt::Display,
+        E: Error,
+    {
+        if self.elements.len() > self.capacity {
+            return Err(E::custom(format_args!("more elements than {} capacity", name)));
+        }
+
+        // the capacity comes from untrusted input, so allocating it must 
+        // fail with an error rather than abort
+        let mut collection = try_new(self.capacity).map_err(E::custom)?;
+
+        for val in self.elements {
+            insert(&mut collection, val).map_err(E::custom)?;
+        }
+
+        Ok(collection)
+    }
+}
@@ -1,6 +1,13 @@
 
-use std::fmt;
-use std::vec::Vec;
+#[cfg(feature = "std")]
+use std::error::Error;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
+use core::ops::{Index, IndexMut};
+use alloc::vec::Vec;
+use crate::collection::{Collection, GrowthPolicy};
 
 /// Error type indicating the stack is full. 
 #[derive(Debug, PartialEq)]
@@ -12,9 +19,34 @@ impl fmt::Display for StackFullError {
     }
 }
 
+#[cfg(feature = "std")]
+impl Error for StackFullError {}
+
+/// Error type indicating the memory for a stack could not be allocated.
+#[derive(Debug, PartialEq)]
+pub struct StackAllocError;
+
+impl fmt::Display for StackAllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stack allocation failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for StackAllocError {}
+
 /// A heap allocated stack that holds elements of type `T`.
+///
+/// The capacity of the underlying buffer is the limit of the stack: 
+/// `push` returns a `StackFullError` once `len() == capacity()`. Anything 
+/// that changes the capacity therefore moves that limit. Both `reserve` 
+/// and `reserve_exact` raise the limit by at least the amount requested; 
+/// `reserve_exact` avoids deliberately over-allocating, but the allocator 
+/// may still hand back more room than asked for.
 pub struct Stack<T> {
-    stack: Vec<T>
+    stack: Vec<T>,
+    growth: Option<GrowthPolicy>,
+    warn_depth: Option<usize>,
 } 
 
 impl<T> Stack<T> {
@@ -28,11 +60,132 @@ impl<T> Stack<T> {
     pub fn new(size: usize) -> Self {
         Stack {
             stack: Vec::with_capacity(size),
+            growth: None,
+            warn_depth: None,
+        }
+    }
+
+    /// Create a new stack with a max capacity of `size`, returning a 
+    /// `StackAllocError` instead of aborting if the memory can not be 
+    /// allocated. Prefer this over `new` when `size` comes from an 
+    /// untrusted source.
+    /// ```
+    /// use rsds::stack::{Stack, StackAllocError};
+    /// 
+    /// let s = Stack::<u32>::try_new(5).unwrap();
+    /// assert_eq!(s.capacity(), 5);
+    /// 
+    /// assert_eq!(Stack::<u32>::try_new(usize::MAX).unwrap_err(), StackAllocError);
+    /// ```
+    pub fn try_new(size: usize) -> Result<Self, StackAllocError> {
+        let mut stack = Vec::new();
+        stack.try_reserve_exact(size).map_err(|_| StackAllocError)?;
+
+        Ok(Stack {
+            stack,
+            growth: None,
+            warn_depth: None,
+        })
+    }
+
+    /// Create a new growable stack. Rather than returning a 
+    /// `StackFullError`, a growable stack reallocates to a larger 
+    /// capacity when a value is pushed onto a full stack, doubling its 
+    /// capacity each time.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new_growable();
+    /// 
+    /// for i in 0..100 {
+    ///     assert_eq!(s.push(i), Ok(()));
+    /// }
+    /// 
+    /// assert_eq!(s.len(), 100);
+    /// ```
+    pub fn new_growable() -> Self {
+        Stack::new_growable_with(0, GrowthPolicy::Double)
+    }
+
+    /// Create a new growable stack with an initial capacity of `initial` 
+    /// that grows according to `policy` when a value is pushed onto a 
+    /// full stack.
+    /// ```
+    /// use rsds::collection::GrowthPolicy;
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new_growable_with(4, GrowthPolicy::OnePointFive);
+    /// 
+    /// for i in 0..5 {
+    ///     assert_eq!(s.push(i), Ok(()));
+    /// }
+    /// 
+    /// assert_eq!(s.capacity(), 6);
+    /// ```
+    pub fn new_growable_with(initial: usize, policy: GrowthPolicy) -> Self {
+        Stack {
+            stack: Vec::with_capacity(initial),
+            growth: Some(policy),
+            warn_depth: None,
         }
     }
     
+    /// Create a new stack from an existing `Vec`, adopting its elements 
+    /// with the last element of the `Vec` on top. The capacity of the 
+    /// stack is set to the length of the `Vec`, so the resulting stack 
+    /// is full and the next `push` will return a `StackFullError`.
+    /// ```
+    /// use rsds::stack::{Stack, StackFullError};
+    /// 
+    /// let mut s = Stack::from_vec(vec![1u32, 2u32, 3u32]);
+    /// 
+    /// assert_eq!(s.len(), 3);
+    /// assert_eq!(s.push(4u32), Err(StackFullError));
+    /// assert_eq!(s.pop(), Some(3u32));
+    /// ```
+    pub fn from_vec(v: Vec<T>) -> Self {
+        Stack {
+            stack: v.into_boxed_slice().into_vec(),
+            growth: None,
+            warn_depth: None,
+        }
+    }
+
+    /// Create a new stack with a max capacity of `size`, filled with 
+    /// values from `iter` in order. If `iter` has more values than fit, 
+    /// the `Err` holds the full stack along with the number of values 
+    /// that could not be placed.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let s = Stack::try_from_iter(5, 0..3u32).unwrap();
+    /// assert_eq!(s.len(), 3);
+    /// assert_eq!(s.capacity(), 5);
+    /// 
+    /// let (s, unplaced) = Stack::try_from_iter(5, 0..8u32).unwrap_err();
+    /// assert!(s.is_full());
+    /// assert_eq!(unplaced, 3);
+    /// ```
+    pub fn try_from_iter(
+        size: usize,
+        iter: impl IntoIterator<Item = T>,
+    ) -> Result<Stack<T>, (Stack<T>, usize)> {
+        let mut stack = Stack::new(size);
+        let mut iter = iter.into_iter();
+
+        for val in iter.by_ref() {
+            if stack.push(val).is_err() {
+                // the value that failed to push is unplaced as well
+                return Err((stack, 1 + iter.count()));
+            }
+        }
+
+        Ok(stack)
+    }
+
     /// Push a value onto the stack if the stack is not full. If the 
-    /// stack is full, a `StackFullError` is returned.
+    /// stack is full, a `StackFullError` is returned, unless the stack 
+    /// was created with `new_growable` in which case it grows instead.
     /// ```
     /// use rsds::stack::{Stack, StackFullError};
     /// 
@@ -50,12 +203,114 @@ impl<T> Stack<T> {
     /// assert_eq!(ret, Err(StackFullError));
     /// ```
     pub fn push(&mut self, val: T) -> Result<(), StackFullError> {
-        if self.stack.len() < self.stack.capacity() {
-            self.stack.push(val);
-            Ok(())
-        } else {
-            Err(StackFullError)
+        if self.stack.len() == self.stack.capacity() {
+            match self.growth {
+                Some(policy) => {
+                    let capacity = policy.next_capacity(self.stack.capacity());
+                    self.stack.reserve_exact(capacity - self.stack.len());
+                }
+                None => return Err(StackFullError),
+            }
+        }
+
+        self.stack.push(val);
+        Ok(())
+    }
+
+    /// Sets a soft depth limit for `push_checked`. Pushes that leave more 
+    /// than `depth` elements on the stack still succeed, but are reported 
+    /// by `push_checked`. The limit has no effect on `push` or on when the 
+    /// stack is considered full.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// s.set_warn_depth(1);
+    /// 
+    /// assert_eq!(s.push_checked(1u32), Ok(false));
+    /// assert_eq!(s.push_checked(2u32), Ok(true));
+    /// ```
+    pub fn set_warn_depth(&mut self, depth: usize) {
+        self.warn_depth = Some(depth);
+    }
+
+    /// Push a value onto the stack like `push`. On success, returns `true` 
+    /// if the stack is now deeper than the depth set with 
+    /// `set_warn_depth`, or `false` if it is not or no depth was set.
+    /// ```
+    /// use rsds::stack::{Stack, StackFullError};
+    /// 
+    /// let mut s = Stack::<u32>::new(2);
+    /// s.set_warn_depth(1);
+    /// 
+    /// assert_eq!(s.push_checked(1u32), Ok(false));
+    /// assert_eq!(s.push_checked(2u32), Ok(true));
+    /// assert_eq!(s.push_checked(3u32), Err(StackFullError));
+    /// ```
+    pub fn push_checked(&mut self, val: T) -> Result<bool, StackFullError> {
+        self.push(val)?;
+
+        Ok(self.warn_depth.is_some_and(|depth| self.stack.len() > depth))
+    }
+
+    /// Push every value from `items` onto the stack in order, returning 
+    /// the number of values pushed. If the stack fills up before `items` 
+    /// is exhausted, the `Err` holds the number of values pushed before 
+    /// the failure along with a `StackFullError`. The values pushed before 
+    /// the error remain on the stack and the rest of `items`, including 
+    /// the value that did not fit, are dropped.
+    /// ```
+    /// use rsds::stack::{Stack, StackFullError};
+    /// 
+    /// let mut s = Stack::<u32>::new(3);
+    /// 
+    /// assert_eq!(s.push_all(vec![1u32, 2u32]), Ok(2));
+    /// assert_eq!(s.push_all(vec![3u32, 4u32]), Err((1, StackFullError)));
+    /// assert_eq!(s.len(), 3);
+    /// ```
+    pub fn push_all(
+        &mut self,
+        items: impl IntoIterator<Item = T>,
+    ) -> Result<usize, (usize, StackFullError)> {
+        let mut pushed = 0;
+
+        for val in items {
+            if let Err(err) = self.push(val) {
+                return Err((pushed, err));
+            }
+
+            pushed += 1;
+        }
+
+        Ok(pushed)
+    }
+
+    /// Push a clone of every value in `items` onto the stack in order. 
+    /// Unlike `push_all`, nothing is pushed unless every value fits: if 
+    /// there is not enough room a `StackFullError` is returned and the 
+    /// stack is left unchanged. A growable stack always has room.
+    /// ```
+    /// use rsds::stack::{Stack, StackFullError};
+    /// 
+    /// let mut s = Stack::<u32>::new(3);
+    /// 
+    /// assert_eq!(s.extend_from_slice(&[1u32, 2u32]), Ok(()));
+    /// assert_eq!(s.extend_from_slice(&[3u32, 4u32]), Err(StackFullError));
+    /// assert_eq!(s.len(), 2);
+    /// ```
+    pub fn extend_from_slice(&mut self, items: &[T]) -> Result<(), StackFullError>
+    where
+        T: Clone,
+    {
+        if self.growth.is_none() && items.len() > self.remaining() {
+            return Err(StackFullError);
+        }
+
+        for val in items {
+            self.push(val.clone())?;
         }
+
+        Ok(())
     }
 
     /// Removes an element from the stack if one exists. 
@@ -83,7 +338,9 @@ impl<T> Stack<T> {
         self.stack.pop()
     }
 
-    /// Returns the current size of the stack as a `usize`.
+    /// Removes and returns the value on top of the stack if `pred` returns 
+    /// `true` for it. Otherwise the stack is left untouched and `None` is 
+    /// returned. `pred` is not called on an empty stack.
     /// ```
     /// use rsds::stack::Stack;
     /// 
@@ -92,110 +349,2863 @@ impl<T> Stack<T> {
     /// s.push(1u32);
     /// s.push(2u32);
     /// 
-    /// // stack should have 2 elements
-    /// let size = s.size();
-    /// assert_eq!(size, 2);
+    /// assert_eq!(s.pop_if(|x| *x == 1), None);
+    /// assert_eq!(s.pop_if(|x| *x == 2), Some(2u32));
     /// ```
-    pub fn size(&self) -> usize {
-        self.stack.len()
+    pub fn pop_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        match self.stack.last() {
+            Some(top) if pred(top) => self.stack.pop(),
+            _ => None,
+        }
     }
-}
-
-
-#[cfg(test)]
-mod test_stack {
-    use crate::stack::*;
-
-    #[test]
-    fn stack_push() {
-        let mut stack = Stack::new(5);
-        
-        let ret = stack.push(542u32);
-        assert_eq!(ret, Ok(()));
 
-        let last_elem = stack.stack.last().clone();
-        assert_eq!(last_elem, Some(&542u32));
+    /// Removes the element at `index`, counting from the bottom, by 
+    /// swapping it with the top and popping it. Returns `None` if `index` 
+    /// is out of range. This does not preserve order: the former top 
+    /// takes the place of the removed element.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// assert_eq!(s.swap_remove(0), Some(1u32));
+    /// assert_eq!(s.pop(), Some(2u32));
+    /// assert_eq!(s.pop(), Some(3u32));
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index < self.stack.len() {
+            Some(self.stack.swap_remove(index))
+        } else {
+            None
+        }
     }
 
-    #[test]
-    fn stack_pop() {
-        let mut stack = Stack::new(5);
-
-        let ret = stack.push(542u32);
-        assert_eq!(ret, Ok(()));
+    /// Removes up to `n` values from the top of the stack and returns them 
+    /// top first, in the same order repeated calls to `pop` would return 
+    /// them. If the stack holds fewer than `n` values, every value is 
+    /// removed.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// assert_eq!(s.pop_n(2), vec![3u32, 2u32]);
+    /// assert_eq!(s.pop_n(2), vec![1u32]);
+    /// ```
+    pub fn pop_n(&mut self, n: usize) -> Vec<T> {
+        let at = self.stack.len().saturating_sub(n);
 
-        let popped_val = stack.pop();
-        assert_eq!(popped_val, Some(542u32));
+        self.stack.drain(at..).rev().collect()
     }
 
-    #[test]
-    fn stack_push_when_full_returns_stackfullerror() {
-        let mut stack = Stack::new(5);
-
-        let mut ret = stack.push(542u32);
-        assert_eq!(ret, Ok(()));
-
-        ret = stack.push(543u32);
-        assert_eq!(ret, Ok(()));
-
-        ret = stack.push(544u32);
-        assert_eq!(ret, Ok(()));
-
-        ret = stack.push(545u32);
-        assert_eq!(ret, Ok(()));
-
-        ret = stack.push(546u32);
-        assert_eq!(ret, Ok(()));
-
-        ret = stack.push(547u32);
-        assert_eq!(ret, Err(StackFullError));
+    /// Returns a reference to the element on top of the stack without 
+    /// removing it, or `None` if the stack is empty.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// assert_eq!(s.peek(), None);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// assert_eq!(s.peek(), Some(&2u32));
+    /// assert_eq!(s.len(), 2);
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.stack.last()
     }
 
-    #[test]
-    fn stack_push_pop_in_correct_order() {
-        let mut stack = Stack::new(5);
-
-        let mut ret = stack.push(542u32);
-        assert_eq!(ret, Ok(()));
-
-        ret = stack.push(543u32);
-        assert_eq!(ret, Ok(()));
-
-        let mut popped_val = stack.pop();
-        assert_eq!(popped_val, Some(543u32));
-
-        popped_val = stack.pop();
-        assert_eq!(popped_val, Some(542u32));
+    /// Returns a reference to the element at the bottom of the stack, the 
+    /// first one pushed, without removing it, or `None` if the stack is 
+    /// empty.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// assert_eq!(s.bottom(), None);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// assert_eq!(s.bottom(), Some(&1u32));
+    /// assert_eq!(s.peek(), Some(&2u32));
+    /// ```
+    pub fn bottom(&self) -> Option<&T> {
+        self.stack.first()
     }
 
-    #[test]
-    fn stack_pop_when_empty_returns_none() {
-        let mut stack = Stack::<u32>::new(5);
-
-        let ret = stack.pop();
-        assert_eq!(ret, None);
+    /// Returns a mutable reference to the element on top of the stack 
+    /// without removing it, or `None` if the stack is empty.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// 
+    /// if let Some(top) = s.peek_mut() {
+    ///     *top = 42u32;
+    /// }
+    /// 
+    /// assert_eq!(s.pop(), Some(42u32));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.stack.last_mut()
     }
 
-    #[test]
-    fn stack_new_returns_empty_stack() {
-        let stack = Stack::<u32>::new(5);
-
-        assert_eq!(stack.stack.len(), 0);
+    /// Returns a reference to the element `n` positions below the top of 
+    /// the stack without removing anything, where `n == 0` is the top. 
+    /// Returns `None` if the stack holds `n` or fewer elements.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// assert_eq!(s.peek_nth(0), Some(&3u32));
+    /// assert_eq!(s.peek_nth(2), Some(&1u32));
+    /// assert_eq!(s.peek_nth(3), None);
+    /// ```
+    pub fn peek_nth(&self, n: usize) -> Option<&T> {
+        if n < self.stack.len() {
+            self.stack.get(self.stack.len() - 1 - n)
+        } else {
+            None
+        }
     }
 
-    #[test]
-    fn stack_new_returns_stack_with_correct_capacity() {
-        let stack = Stack::<u32>::new(5);
-
-        assert_eq!(stack.stack.capacity(), 5);
+    /// Returns the number of elements in the stack as a `usize`. This is 
+    /// never more than the capacity of the stack.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// // stack should have 2 elements
+    /// assert_eq!(s.len(), 2);
+    /// assert_eq!(s.capacity(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.stack.len()
     }
 
-    #[test]
-    #[should_panic]
-    fn stack_allocate_max_isize_should_panic() {
-        // panic - trying to allocate too much memory
-        let stack = Stack::<u32>::new(isize::MAX as usize); 
-        
-        assert_eq!(stack.stack.capacity(), isize::MAX as usize);
+    /// Returns the current size of the stack as a `usize`.
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// // stack should have 2 elements
+    /// let size = s.size();
+    /// assert_eq!(size, 2);
+    /// ```
+    #[deprecated(note = "use len()")]
+    pub fn size(&self) -> usize {
+        self.len()
+    }
+
+    /// Returns the max capacity of the stack as a `usize`.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// 
+    /// assert_eq!(s.capacity(), 5);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.stack.capacity()
+    }
+
+    /// Returns the number of elements that fit in the current capacity. 
+    /// For a bounded stack this is how many more values can be pushed 
+    /// before `push` returns a `StackFullError`. A growable stack never 
+    /// errors, so for it this is the room left before the next 
+    /// reallocation.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// 
+    /// assert_eq!(s.remaining(), 4);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.stack.capacity() - self.stack.len()
+    }
+
+    /// Reserves capacity for at least `additional` more elements. Since 
+    /// `push` only fails once the stack reaches its capacity, reserving 
+    /// more room raises the point at which the stack is considered full. 
+    /// The underlying allocation may be larger than requested, so the 
+    /// new capacity can exceed `len() + additional`.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(1);
+    /// 
+    /// s.push(1u32);
+    /// assert!(s.is_full());
+    /// 
+    /// s.reserve(10);
+    /// assert!(s.capacity() >= 11);
+    /// assert_eq!(s.push(2u32), Ok(()));
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.stack.reserve(additional);
+    }
+
+    /// Reserves the minimum room for `additional` more elements beyond the 
+    /// current length, without deliberately over-allocating. A full stack 
+    /// then accepts at least `additional` more pushes before returning a 
+    /// `StackFullError`. The allocator may still provide more room than 
+    /// requested, so check `capacity()` for the new limit rather than 
+    /// relying on it being exactly `len() + additional`. Has no effect if 
+    /// the capacity is already large enough.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(1);
+    /// 
+    /// s.push(1u32);
+    /// s.reserve_exact(1);
+    /// 
+    /// assert!(s.capacity() >= 2);
+    /// assert_eq!(s.push(2u32), Ok(()));
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.stack.reserve_exact(additional);
+    }
+
+    /// Shrinks the capacity of the stack as close to its current size 
+    /// as possible. Since capacity is the limit checked by `push`, this 
+    /// lowers the point at which the stack is considered full, usually 
+    /// leaving the stack full.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.shrink_to_fit();
+    /// 
+    /// assert_eq!(s.capacity(), 1);
+    /// assert!(s.is_full());
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.stack.shrink_to_fit();
+    }
+
+    /// Returns the approximate number of bytes used by the stack: the size 
+    /// of the `Stack` itself plus its buffer of `capacity()` elements. 
+    /// Memory owned by the elements themselves, such as the contents of a 
+    /// `String`, is not counted.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let s = Stack::<u64>::new(4);
+    /// 
+    /// assert!(s.memory_usage() >= 4 * 8);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>() + self.stack.capacity() * core::mem::size_of::<T>()
+    }
+
+    /// Removes all elements from the stack. The capacity of the stack 
+    /// is unchanged.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// s.clear();
+    /// assert_eq!(s.len(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
+
+    /// Shortens the stack to `new_len` elements, dropping everything above 
+    /// that depth. Has no effect if `new_len` is greater than or equal to 
+    /// the current length. The capacity of the stack is unchanged.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// s.truncate(1);
+    /// assert_eq!(s.len(), 1);
+    /// assert_eq!(s.pop(), Some(1u32));
+    /// assert_eq!(s.capacity(), 5);
+    /// ```
+    pub fn truncate(&mut self, new_len: usize) {
+        self.stack.truncate(new_len);
+    }
+
+    /// Reverses the order of the elements in place, so the former top 
+    /// becomes the bottom. No reallocation takes place.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// s.reverse();
+    /// assert_eq!(s.pop(), Some(1u32));
+    /// assert_eq!(s.pop(), Some(2u32));
+    /// assert_eq!(s.pop(), Some(3u32));
+    /// ```
+    pub fn reverse(&mut self) {
+        self.stack.reverse();
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each 
+    /// run. The capacity of the stack is unchanged.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::from([1u32, 1u32, 2u32, 1u32]);
+    /// 
+    /// s.dedup();
+    /// assert_eq!(s.len(), 3);
+    /// assert_eq!(s.pop(), Some(1u32));
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.stack.dedup();
+    }
+
+    /// Removes every element for which `pred` returns `true` and returns 
+    /// them in a `Vec`. Removed elements are returned in bottom to top 
+    /// order, so the last element of the `Vec` is the one nearest the top. 
+    /// The remaining elements keep their bottom to top order and the 
+    /// capacity of the stack is unchanged.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// s.push(4u32);
+    /// 
+    /// let evens = s.extract_if(|x| x % 2 == 0);
+    /// assert_eq!(evens, vec![2u32, 4u32]);
+    /// 
+    /// assert_eq!(s.pop(), Some(3u32));
+    /// assert_eq!(s.pop(), Some(1u32));
+    /// ```
+    pub fn extract_if<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        self.stack.extract_if(.., |val| pred(val)).collect()
+    }
+
+    /// Splits the stack in two at index `at`, counting from the bottom. 
+    /// Elements `at..len()` are moved into a new stack, keeping their 
+    /// order, while elements `0..at` stay in `self`. The new stack has 
+    /// room for the moved elements and is growable if `self` is.
+    ///
+    /// Panics if `at` is greater than `len()`.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// let mut top = s.split_off(1);
+    /// assert_eq!(top.pop(), Some(3u32));
+    /// assert_eq!(top.pop(), Some(2u32));
+    /// assert_eq!(s.pop(), Some(1u32));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Stack<T> {
+        Stack {
+            stack: self.stack.split_off(at),
+            growth: self.growth,
+            warn_depth: self.warn_depth,
+        }
+    }
+
+    /// Consumes the stack, returning its elements sorted in ascending 
+    /// order. The sort is stable, so equal elements keep their bottom to 
+    /// top order.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let s = Stack::from([3u32, 1u32, 2u32]);
+    /// 
+    /// assert_eq!(s.into_sorted_vec(), vec![1u32, 2u32, 3u32]);
+    /// ```
+    pub fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        self.into_sorted_vec_by(T::cmp)
+    }
+
+    /// Consumes the stack, returning its elements sorted with the 
+    /// comparator `compare`. The sort is stable, so elements `compare` 
+    /// considers equal keep their bottom to top order.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let s = Stack::from([3u32, 1u32, 2u32]);
+    /// 
+    /// // sort in descending order
+    /// assert_eq!(s.into_sorted_vec_by(|a, b| b.cmp(a)), vec![3u32, 2u32, 1u32]);
+    /// ```
+    pub fn into_sorted_vec_by<F: FnMut(&T, &T) -> Ordering>(self, compare: F) -> Vec<T> {
+        let mut v = self.stack;
+        v.sort_by(compare);
+
+        v
+    }
+
+    /// Returns `true` if the stack contains no elements.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// assert!(s.is_empty());
+    /// 
+    /// s.push(1u32);
+    /// assert!(!s.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Returns `true` if the next `push` will return a `StackFullError`, 
+    /// which is when a bounded stack is at capacity. A growable stack 
+    /// reallocates rather than erroring, so it is never full.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(2);
+    /// 
+    /// s.push(1u32);
+    /// assert!(!s.is_full());
+    /// 
+    /// s.push(2u32);
+    /// assert!(s.is_full());
+    /// 
+    /// let mut g = Stack::<u32>::new_growable();
+    /// 
+    /// g.push(1u32);
+    /// assert!(!g.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.growth.is_none() && self.stack.len() == self.stack.capacity()
+    }
+
+    /// Returns `true` if the stack contains an element equal to `val`.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// 
+    /// assert!(s.contains(&1u32));
+    /// assert!(!s.contains(&2u32));
+    /// ```
+    pub fn contains(&self, val: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.stack.contains(val)
+    }
+
+    /// Returns an iterator over the stack from bottom to top. The first
+    /// element yielded is the first one pushed and the last element 
+    /// yielded is the one `pop` would return next.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// let items: Vec<&u32> = s.iter().collect();
+    /// assert_eq!(items, vec![&1u32, &2u32, &3u32]);
+    /// 
+    /// // the stack is only borrowed and is left untouched
+    /// assert_eq!(s.len(), 3);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.stack.iter()
+    }
+
+    /// Returns an iterator over the stack from top to bottom. Elements are 
+    /// yielded in the same order repeated calls to `pop` would return them.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// let items: Vec<&u32> = s.iter_top_down().collect();
+    /// assert_eq!(items, vec![&3u32, &2u32, &1u32]);
+    /// ```
+    pub fn iter_top_down(&self) -> impl Iterator<Item = &T> {
+        self.stack.iter().rev()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    /// Creates an empty stack with a max capacity of zero. The stack is 
+    /// full as soon as it is created, so `push` returns a `StackFullError` 
+    /// until room is made with `reserve`.
+    /// ```
+    /// use rsds::stack::{Stack, StackFullError};
+    /// 
+    /// let mut s = Stack::<u32>::default();
+    /// assert_eq!(s.push(1u32), Err(StackFullError));
+    /// 
+    /// s.reserve(1);
+    /// assert_eq!(s.push(1u32), Ok(()));
+    /// ```
+    fn default() -> Self {
+        Stack::new(0)
+    }
+}
+
+impl<T: Clone> Clone for Stack<T> {
+    /// Returns a copy of the stack with the same elements and the same 
+    /// max capacity. A derived `Clone` would not work here since cloning 
+    /// a `Vec` only allocates room for its length.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// 
+    /// let c = s.clone();
+    /// assert_eq!(c.len(), 1);
+    /// assert_eq!(c.capacity(), 5);
+    /// ```
+    fn clone(&self) -> Self {
+        let mut stack = Vec::with_capacity(self.stack.capacity());
+        stack.extend(self.stack.iter().cloned());
+
+        Stack {
+            stack,
+            growth: self.growth,
+            warn_depth: self.warn_depth,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Stack<T> {
+    /// Two stacks are equal when they hold the same elements in the same 
+    /// order. Capacity is not compared.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut a = Stack::<u32>::new(3);
+    /// let mut b = Stack::<u32>::new(5);
+    /// 
+    /// a.push(1u32);
+    /// b.push(1u32);
+    /// 
+    /// assert!(a == b);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.stack == other.stack
+    }
+}
+
+impl<T: Eq> Eq for Stack<T> {}
+
+impl<T: PartialOrd> PartialOrd for Stack<T> {
+    /// Stacks are ordered lexicographically by their elements from bottom 
+    /// to top. The first differing element decides the order, and if one 
+    /// stack is a prefix of the other the shorter stack is less. Capacity 
+    /// is not compared.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let a = Stack::from([1u32, 2u32]);
+    /// let b = Stack::from([1u32, 3u32]);
+    /// let c = Stack::from([1u32, 2u32, 0u32]);
+    /// 
+    /// assert!(a < b);
+    /// assert!(a < c);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.stack.partial_cmp(&other.stack)
+    }
+}
+
+impl<T: Ord> Ord for Stack<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.stack.cmp(&other.stack)
+    }
+}
+
+impl<T: Hash> Hash for Stack<T> {
+    /// Hashes the elements from bottom to top. Capacity is not hashed, so 
+    /// stacks that compare equal hash equally.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.stack.hash(state);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Stack<T> {
+    /// Formats the stack showing its size, capacity, and elements from 
+    /// bottom to top.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// assert_eq!(format!("{:?}", s), "Stack { size: 2, capacity: 5, elements: [1, 2] }");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stack")
+            .field("size", &self.stack.len())
+            .field("capacity", &self.stack.capacity())
+            .field("elements", &self.stack)
+            .finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Stack<T> {
+    /// Formats the elements of the stack from bottom to top as a 
+    /// bracketed list.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// assert_eq!(s.to_string(), "[1, 2, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+
+        for (i, val) in self.stack.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", val)?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+impl<T> Extend<T> for Stack<T> {
+    /// Pushes values from the iterator onto the stack until it is full. 
+    /// Unlike `push_all`, no error is reported and any values that do not 
+    /// fit are silently dropped. A growable stack grows to fit every value.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(3);
+    /// 
+    /// s.extend(0..5);
+    /// 
+    /// assert_eq!(s.len(), 3);
+    /// assert_eq!(s.pop(), Some(2u32));
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            if self.push(val).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    /// Collects an iterator into a stack, pushing elements in iteration 
+    /// order. As with `from_vec`, the capacity is set to the number of 
+    /// collected elements so the resulting stack is full.
+    /// ```
+    /// use rsds::stack::{Stack, StackFullError};
+    /// 
+    /// let mut s: Stack<u32> = (0..5).collect();
+    /// 
+    /// assert_eq!(s.len(), 5);
+    /// assert_eq!(s.push(5u32), Err(StackFullError));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Stack::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Stack<T> {
+    /// Creates a stack from an array, pushing elements in array order so 
+    /// the last element is on top. The capacity is set to `N`, so the 
+    /// resulting stack is full.
+    /// ```
+    /// use rsds::stack::{Stack, StackFullError};
+    /// 
+    /// let mut s = Stack::from([1u32, 2u32, 3u32]);
+    /// 
+    /// assert_eq!(s.push(4u32), Err(StackFullError));
+    /// assert_eq!(s.pop(), Some(3u32));
+    /// ```
+    fn from(arr: [T; N]) -> Self {
+        Stack::from_vec(Vec::from(arr))
+    }
+}
+
+impl<T> Index<usize> for Stack<T> {
+    type Output = T;
+
+    /// Returns a reference to the element at `index`, counting from the 
+    /// bottom of the stack. Index `0` is the first element pushed and 
+    /// `len() - 1` is the top.
+    ///
+    /// Panics if `index` is out of range.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// assert_eq!(s[0], 1u32);
+    /// assert_eq!(s[1], 2u32);
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        assert!(index < self.stack.len(), "index {} out of range for stack of length {}", index, self.stack.len());
+
+        &self.stack[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Stack<T> {
+    /// Returns a mutable reference to the element at `index`, counting from 
+    /// the bottom of the stack.
+    ///
+    /// Panics if `index` is out of range.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// s[0] = 10u32;
+    /// assert_eq!(s.pop(), Some(2u32));
+    /// assert_eq!(s.pop(), Some(10u32));
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        assert!(index < self.stack.len(), "index {} out of range for stack of length {}", index, self.stack.len());
+
+        &mut self.stack[index]
+    }
+}
+
+/// Pushes bytes onto the stack in order, so the last byte written is on 
+/// top. Bytes are pushed until the stack is full and the number pushed is 
+/// returned, so a write may be short. Once the stack is full `write` 
+/// returns `Ok(0)`, which `write_all` reports as `ErrorKind::WriteZero`.
+///
+/// Since the stack is LIFO, popping the bytes back off returns them in 
+/// reverse order.
+/// ```
+/// use rsds::stack::Stack;
+/// use std::io::Write;
+/// 
+/// let mut s = Stack::<u8>::new(3);
+/// 
+/// assert_eq!(s.write(b"abcd").unwrap(), 3);
+/// assert_eq!(s.pop(), Some(b'c'));
+/// ```
+#[cfg(feature = "std")]
+impl std::io::Write for Stack<u8> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut written = 0;
+
+        for byte in buf {
+            if self.push(*byte).is_err() {
+                break;
+            }
+
+            written += 1;
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Serializes the stack as its capacity, its elements from bottom to top 
+/// and, for a growable stack, its `GrowthPolicy`. The depth set with 
+/// `set_warn_depth` is a runtime diagnostic and is not serialized.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Stack<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let len = 2 + usize::from(self.growth.is_some());
+        let mut state = serializer.serialize_struct("Stack", len)?;
+        state.serialize_field("capacity", &self.stack.capacity())?;
+        state.serialize_field("elements", &self.stack)?;
+        match &self.growth {
+            Some(policy) => state.serialize_field("growth", policy)?,
+            None => state.skip_field("growth")?,
+        }
+        state.end()
+    }
+}
+
+/// Deserializes a stack from its capacity, its elements from bottom to 
+/// top and an optional `GrowthPolicy`, which makes the stack growable. 
+/// An error is returned if there are more elements than capacity or if 
+/// the capacity can not be allocated.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Stack<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = crate::serde_support::Repr::<T>::deserialize(deserializer)?;
+        let growth = repr.growth;
+
+        repr.build(
+            "stack",
+            |cap| Stack::try_new(cap).map(|s| Stack { growth, ..s }),
+            |c, val| c.push(val),
+        )
+    }
+}
+
+/// An owning iterator over the elements of a `Stack`, yielding them in 
+/// the order `pop` would return them (top first). Created by calling
+/// `into_iter` on a `Stack`.
+pub struct StackIntoIter<T> {
+    stack: Vec<T>
+}
+
+impl<T> Iterator for StackIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.stack.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.stack.len(), Some(self.stack.len()))
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = StackIntoIter<T>;
+
+    /// Consumes the stack, returning an iterator that yields its elements 
+    /// top first.
+    /// ```
+    /// use rsds::stack::Stack;
+    /// 
+    /// let mut s = Stack::<u32>::new(5);
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// s.push(3u32);
+    /// 
+    /// let items: Vec<u32> = s.into_iter().collect();
+    /// assert_eq!(items, vec![3u32, 2u32, 1u32]);
+    /// ```
+    fn into_iter(self) -> StackIntoIter<T> {
+        StackIntoIter { stack: self.stack }
+    }
+}
+
+
+impl<T> Collection<T> for Stack<T> {
+    fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.stack.capacity()
+    }
+
+    fn is_full(&self) -> bool {
+        Stack::is_full(self)
+    }
+
+    fn clear(&mut self) {
+        Stack::clear(self)
+    }
+}
+
+/// A stack that holds up to `N` elements of type `T` inline, without any 
+/// heap allocation. It behaves like a `Stack` created with `Stack::new(N)`, 
+/// returning a `StackFullError` once `N` elements have been pushed.
+pub struct ArrayStack<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayStack<T, N> {
+    /// Create a new empty stack with a max capacity of `N`.
+    /// ```
+    /// use rsds::stack::ArrayStack;
+    /// 
+    /// // Initialize an empty stack with room for 5 `u32`'s.
+    /// let mut s = ArrayStack::<u32, 5>::new();
+    /// ```
+    pub fn new() -> Self {
+        ArrayStack {
+            data: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Push a value onto the stack if the stack is not full, otherwise 
+    /// return a `StackFullError`.
+    /// ```
+    /// use rsds::stack::{ArrayStack, StackFullError};
+    /// 
+    /// let mut s = ArrayStack::<u32, 1>::new();
+    /// 
+    /// assert_eq!(s.push(1u32), Ok(()));
+    /// assert_eq!(s.push(2u32), Err(StackFullError));
+    /// ```
+    pub fn push(&mut self, val: T) -> Result<(), StackFullError> {
+        if self.len == N {
+            return Err(StackFullError);
+        }
+
+        self.data[self.len].write(val);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes an element from the stack if one exists. 
+    /// Returns `Some(T)` or `None` if the stack is empty.
+    /// ```
+    /// use rsds::stack::ArrayStack;
+    /// 
+    /// let mut s = ArrayStack::<u32, 5>::new();
+    /// 
+    /// s.push(1u32);
+    /// s.push(2u32);
+    /// 
+    /// assert_eq!(s.pop(), Some(2u32));
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        // SAFETY: slots below the old `len` are initialized, and lowering 
+        // `len` first means this slot is never read or dropped again
+        Some(unsafe { self.data[self.len].assume_init_read() })
+    }
+
+    /// Returns a reference to the element on top of the stack without 
+    /// removing it, or `None` if the stack is empty.
+    /// ```
+    /// use rsds::stack::ArrayStack;
+    /// 
+    /// let mut s = ArrayStack::<u32, 5>::new();
+    /// assert_eq!(s.peek(), None);
+    /// 
+    /// s.push(1u32);
+    /// assert_eq!(s.peek(), Some(&1u32));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: slots below `len` are initialized
+        Some(unsafe { self.data[self.len - 1].assume_init_ref() })
+    }
+
+    /// Returns the number of elements in the stack as a `usize`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the max capacity of the stack, which is always `N`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the stack contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the stack holds `N` elements, meaning the next 
+    /// `push` will return a `StackFullError`.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+}
+
+impl<T, const N: usize> Default for ArrayStack<T, N> {
+    fn default() -> Self {
+        ArrayStack::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayStack<T, N> {
+    fn drop(&mut self) {
+        for slot in &mut self.data[..self.len] {
+            // SAFETY: slots below `len` are initialized and are not used 
+            // again after the stack is dropped
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_stack {
+    use crate::stack::*;
+
+    #[test]
+    fn stack_push() {
+        let mut stack = Stack::new(5);
+        
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        let last_elem = stack.stack.last();
+        assert_eq!(last_elem, Some(&542u32));
+    }
+
+    #[test]
+    fn stack_pop() {
+        let mut stack = Stack::new(5);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        let popped_val = stack.pop();
+        assert_eq!(popped_val, Some(542u32));
+    }
+
+    #[test]
+    fn stack_push_when_full_returns_stackfullerror() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(544u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(545u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(546u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(547u32);
+        assert_eq!(ret, Err(StackFullError));
+    }
+
+    #[test]
+    fn stack_push_pop_in_correct_order() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        let mut popped_val = stack.pop();
+        assert_eq!(popped_val, Some(543u32));
+
+        popped_val = stack.pop();
+        assert_eq!(popped_val, Some(542u32));
+    }
+
+    #[test]
+    fn stack_pop_when_empty_returns_none() {
+        let mut stack = Stack::<u32>::new(5);
+
+        let ret = stack.pop();
+        assert_eq!(ret, None);
+    }
+
+    #[test]
+    fn stack_new_returns_empty_stack() {
+        let stack = Stack::<u32>::new(5);
+
+        assert_eq!(stack.stack.len(), 0);
+    }
+
+    #[test]
+    fn stack_new_returns_stack_with_correct_capacity() {
+        let stack = Stack::<u32>::new(5);
+
+        assert_eq!(stack.stack.capacity(), 5);
+    }
+
+    #[test]
+    fn stack_iter_yields_bottom_to_top() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(544u32);
+        assert_eq!(ret, Ok(()));
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&542u32, &543u32, &544u32]);
+        assert_eq!(stack.len(), 3);
+    }
+
+    #[test]
+    fn stack_iter_top_down_yields_pop_order() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(544u32);
+        assert_eq!(ret, Ok(()));
+
+        let items: Vec<u32> = stack.iter_top_down().copied().collect();
+        assert_eq!(items, vec![544u32, 543u32, 542u32]);
+        assert_eq!(stack.len(), 3);
+
+        for item in items {
+            assert_eq!(stack.pop(), Some(item));
+        }
+    }
+
+    #[test]
+    fn stack_iter_when_empty_yields_nothing() {
+        let stack = Stack::<u32>::new(5);
+
+        assert_eq!(stack.iter().count(), 0);
+        assert_eq!(stack.iter_top_down().count(), 0);
+        assert_eq!(stack.len(), 0);
+    }
+
+    #[test]
+    fn stack_into_iter_yields_pop_order() {
+        let mut stack = Stack::new(5);
+        let mut expected = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            assert_eq!(stack.push(val), Ok(()));
+            assert_eq!(expected.push(val), Ok(()));
+        }
+
+        let mut iter = stack.into_iter();
+        assert_eq!(iter.next(), expected.pop());
+        assert_eq!(iter.next(), expected.pop());
+        assert_eq!(iter.next(), expected.pop());
+        assert_eq!(iter.next(), None);
+        assert_eq!(expected.pop(), None);
+    }
+
+    #[test]
+    fn stack_into_iter_in_for_loop_is_lifo() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        let mut items = Vec::new();
+        for item in stack {
+            items.push(item);
+        }
+
+        assert_eq!(items, vec![543u32, 542u32]);
+    }
+
+    #[test]
+    fn stack_into_iter_when_empty_returns_none() {
+        let stack = Stack::<u32>::new(5);
+
+        let mut iter = stack.into_iter();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn stack_from_vec_is_full() {
+        let mut stack = Stack::from_vec(vec![542u32, 543u32, 544u32]);
+
+        assert_eq!(stack.stack.len(), 3);
+        assert_eq!(stack.stack.capacity(), 3);
+
+        let ret = stack.push(545u32);
+        assert_eq!(ret, Err(StackFullError));
+    }
+
+    #[test]
+    fn stack_from_vec_with_spare_capacity_is_full() {
+        let mut v = Vec::with_capacity(10);
+        v.push(542u32);
+        v.push(543u32);
+
+        let mut stack = Stack::from_vec(v);
+
+        assert_eq!(stack.stack.capacity(), 2);
+
+        let ret = stack.push(544u32);
+        assert_eq!(ret, Err(StackFullError));
+
+        let popped_val = stack.pop();
+        assert_eq!(popped_val, Some(543u32));
+    }
+
+    #[test]
+    fn stack_from_iter_is_full() {
+        let mut stack: Stack<u32> = (0..5).collect();
+
+        assert_eq!(stack.stack.len(), 5);
+        assert_eq!(stack.stack.capacity(), 5);
+
+        let ret = stack.push(5u32);
+        assert_eq!(ret, Err(StackFullError));
+
+        let popped_val = stack.pop();
+        assert_eq!(popped_val, Some(4u32));
+    }
+
+    #[test]
+    fn stack_from_empty_iter_is_full() {
+        let mut stack: Stack<u32> = std::iter::empty().collect();
+
+        assert_eq!(stack.stack.capacity(), 0);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Err(StackFullError));
+    }
+
+    #[test]
+    fn stack_peek_when_empty_returns_none() {
+        let mut stack = Stack::<u32>::new(5);
+
+        assert_eq!(stack.peek(), None);
+        assert_eq!(stack.peek_mut(), None);
+    }
+
+    #[test]
+    fn stack_peek_returns_ref_to_top() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(stack.peek(), Some(&542u32));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(stack.peek(), Some(&543u32));
+        assert_eq!(stack.stack.len(), 2);
+    }
+
+    #[test]
+    fn stack_peek_mut_modifies_top_in_place() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        if let Some(top) = stack.peek_mut() {
+            *top += 100;
+        }
+
+        let mut popped_val = stack.pop();
+        assert_eq!(popped_val, Some(643u32));
+
+        popped_val = stack.pop();
+        assert_eq!(popped_val, Some(542u32));
+    }
+
+    #[test]
+    fn stack_is_empty_tracks_push_and_pop() {
+        let mut stack = Stack::new(5);
+        assert!(stack.is_empty());
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+        assert!(!stack.is_empty());
+
+        let popped_val = stack.pop();
+        assert_eq!(popped_val, Some(542u32));
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn stack_is_full_exactly_at_capacity() {
+        let mut stack = Stack::new(3);
+        assert!(!stack.is_full());
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+        assert!(!stack.is_full());
+
+        ret = stack.push(544u32);
+        assert_eq!(ret, Ok(()));
+        assert!(stack.is_full());
+
+        ret = stack.push(545u32);
+        assert_eq!(ret, Err(StackFullError));
+        assert!(stack.is_full());
+
+        stack.pop();
+        assert!(!stack.is_full());
+    }
+
+    #[test]
+    fn stack_clear_preserves_capacity() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        stack.clear();
+        assert_eq!(stack.len(), 0);
+        assert_eq!(stack.stack.capacity(), 5);
+
+        ret = stack.push(544u32);
+        assert_eq!(ret, Ok(()));
+
+        let popped_val = stack.pop();
+        assert_eq!(popped_val, Some(544u32));
+
+        let popped_val = stack.pop();
+        assert_eq!(popped_val, None);
+    }
+
+    #[test]
+    fn stack_capacity_unchanged_by_push_pop_clear() {
+        let mut stack = Stack::new(5);
+        assert_eq!(stack.capacity(), 5);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(stack.capacity(), 5);
+
+        stack.pop();
+        assert_eq!(stack.capacity(), 5);
+
+        let ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        stack.clear();
+        assert_eq!(stack.capacity(), 5);
+    }
+
+    #[test]
+    fn stack_debug_shows_bottom_to_top() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(
+            format!("{:?}", stack),
+            "Stack { size: 2, capacity: 5, elements: [542, 543] }"
+        );
+    }
+
+    #[test]
+    fn stack_debug_when_empty() {
+        let stack = Stack::<u32>::new(5);
+
+        assert_eq!(
+            format!("{:?}", stack),
+            "Stack { size: 0, capacity: 5, elements: [] }"
+        );
+    }
+
+    #[test]
+    fn stack_clone_preserves_capacity_and_order() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        let mut cloned = stack.clone();
+        assert_eq!(cloned.stack.capacity(), 5);
+        assert_eq!(cloned.stack.len(), 2);
+
+        ret = cloned.push(544u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(cloned.pop(), Some(544u32));
+        assert_eq!(cloned.pop(), stack.pop());
+        assert_eq!(cloned.pop(), stack.pop());
+        assert_eq!(cloned.pop(), None);
+    }
+
+    #[test]
+    fn stack_eq_compares_contents_not_capacity() {
+        let mut a = Stack::new(5);
+        let mut b = Stack::new(3);
+
+        assert!(a == b);
+
+        let mut ret = a.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = a.push(543u32);
+        assert_eq!(ret, Ok(()));
+        assert!(a != b);
+
+        ret = b.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = b.push(544u32);
+        assert_eq!(ret, Ok(()));
+        assert!(a != b);
+
+        b.pop();
+        ret = b.push(543u32);
+        assert_eq!(ret, Ok(()));
+        assert!(a == b);
+    }
+
+    #[test]
+    fn stack_display_when_empty() {
+        let stack = Stack::<u32>::new(5);
+
+        assert_eq!(stack.to_string(), "[]");
+    }
+
+    #[test]
+    fn stack_display_single_element() {
+        let mut stack = Stack::new(5);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(stack.to_string(), "[542]");
+    }
+
+    #[test]
+    fn stack_display_bottom_to_top() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(544u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(stack.to_string(), "[542, 543, 544]");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn stack_serde_round_trip() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        let json = serde_json::to_string(&stack).unwrap();
+        assert_eq!(json, r#"{"capacity":5,"elements":[542,543]}"#);
+
+        let mut restored: Stack<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.stack.capacity(), 5);
+        assert_eq!(restored.pop(), Some(543u32));
+        assert_eq!(restored.pop(), Some(542u32));
+        assert_eq!(restored.pop(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn stack_deserialize_more_elements_than_capacity_errors() {
+        let json = r#"{"capacity":1,"elements":[542,543]}"#;
+
+        let ret = serde_json::from_str::<Stack<u32>>(json);
+        assert!(ret.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn stack_deserialize_absurd_capacity_errors() {
+        let json = r#"{"capacity":18446744073709551615,"elements":[]}"#;
+
+        let ret = serde_json::from_str::<Stack<u32>>(json);
+        assert!(ret.unwrap_err().to_string().contains("stack allocation failed"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn stack_serde_round_trip_growable() {
+        let mut stack = Stack::new_growable_with(2, GrowthPolicy::Fixed(3));
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        let json = serde_json::to_string(&stack).unwrap();
+        assert_eq!(json, r#"{"capacity":2,"elements":[542],"growth":{"Fixed":3}}"#);
+
+        let mut restored: Stack<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.capacity(), 2);
+
+        for i in 0..4u32 {
+            ret = restored.push(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(restored.capacity(), 5);
+        assert_eq!(restored.len(), 5);
+        assert!(!restored.is_full());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn stack_serde_does_not_persist_warn_depth() {
+        let mut stack = Stack::<u32>::new(5);
+        stack.set_warn_depth(0);
+
+        let json = serde_json::to_string(&stack).unwrap();
+        let mut restored: Stack<u32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.push_checked(542u32), Ok(false));
+    }
+
+    #[test]
+    fn stack_growable_push_past_capacity() {
+        let mut stack = Stack::new_growable();
+
+        let mut ret = stack.push(0u32);
+        assert_eq!(ret, Ok(()));
+
+        let initial_capacity = stack.capacity();
+
+        for i in 1..=initial_capacity as u32 {
+            ret = stack.push(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert!(stack.capacity() > initial_capacity);
+        assert_eq!(stack.len(), initial_capacity + 1);
+
+        for i in (0..=initial_capacity as u32).rev() {
+            assert_eq!(stack.pop(), Some(i));
+        }
+
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn stack_growable_clone_is_growable() {
+        let stack = Stack::<u32>::new_growable();
+        let mut cloned = stack.clone();
+
+        for i in 0..100u32 {
+            let ret = cloned.push(i);
+            assert_eq!(ret, Ok(()));
+        }
+    }
+
+    #[test]
+    fn stack_from_vec_is_not_growable() {
+        let mut stack = Stack::from_vec(vec![542u32]);
+
+        let ret = stack.push(543u32);
+        assert_eq!(ret, Err(StackFullError));
+    }
+
+    #[test]
+    fn stack_reserve_allows_pushes_when_full() {
+        let mut stack = Stack::new(2);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(544u32);
+        assert_eq!(ret, Err(StackFullError));
+
+        stack.reserve(10);
+        assert!(stack.capacity() >= 12);
+
+        for i in 0..10u32 {
+            ret = stack.push(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.len(), 12);
+        assert_eq!(stack.pop(), Some(9u32));
+    }
+
+    #[test]
+    fn stack_shrink_to_fit_lowers_capacity() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        stack.shrink_to_fit();
+        assert_eq!(stack.capacity(), 2);
+
+        ret = stack.push(544u32);
+        assert_eq!(ret, Err(StackFullError));
+
+        assert_eq!(stack.pop(), Some(543u32));
+        assert_eq!(stack.pop(), Some(542u32));
+    }
+
+    #[test]
+    fn stack_len_tracks_push_and_pop() {
+        let mut stack = Stack::new(5);
+        assert_eq!(stack.len(), 0);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(stack.len(), 1);
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(stack.len(), 2);
+
+        stack.pop();
+        assert_eq!(stack.len(), 1);
+
+        stack.pop();
+        stack.pop();
+        assert_eq!(stack.len(), 0);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn stack_size_matches_len() {
+        let mut stack = Stack::new(5);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(stack.size(), stack.len());
+    }
+
+    #[test]
+    fn stack_contains_only_pushed_values() {
+        let mut stack = Stack::new(5);
+        assert!(!stack.contains(&542u32));
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        assert!(stack.contains(&542u32));
+        assert!(stack.contains(&543u32));
+        assert!(!stack.contains(&544u32));
+
+        stack.pop();
+        assert!(!stack.contains(&543u32));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn stack_full_error_propagates_as_boxed_error() {
+        fn overflow() -> Result<(), Box<dyn std::error::Error>> {
+            let mut stack = Stack::new(1);
+
+            stack.push(542u32)?;
+            stack.push(543u32)?;
+
+            Ok(())
+        }
+
+        let err = overflow().unwrap_err();
+        assert_eq!(err.to_string(), "stack is full");
+        assert!(err.downcast_ref::<StackFullError>().is_some());
+    }
+
+    #[test]
+    fn stack_extend_within_capacity() {
+        let mut stack = Stack::new(5);
+
+        stack.extend(vec![542u32, 543u32]);
+
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.pop(), Some(543u32));
+        assert_eq!(stack.pop(), Some(542u32));
+    }
+
+    #[test]
+    fn stack_extend_exactly_to_capacity() {
+        let mut stack = Stack::new(3);
+
+        stack.extend(vec![542u32, 543u32, 544u32]);
+
+        assert!(stack.is_full());
+        assert_eq!(stack.pop(), Some(544u32));
+    }
+
+    #[test]
+    fn stack_extend_past_capacity_stops_at_full() {
+        let mut stack = Stack::new(3);
+
+        stack.extend(0..10u32);
+
+        assert!(stack.is_full());
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(2u32));
+        assert_eq!(stack.pop(), Some(1u32));
+        assert_eq!(stack.pop(), Some(0u32));
+    }
+
+    #[test]
+    fn stack_extend_growable_takes_everything() {
+        let mut stack = Stack::new_growable();
+
+        stack.extend(0..10u32);
+
+        assert_eq!(stack.len(), 10);
+        assert_eq!(stack.pop(), Some(9u32));
+    }
+
+    #[test]
+    fn stack_push_all_within_capacity() {
+        let mut stack = Stack::new(5);
+
+        let ret = stack.push_all(vec![542u32, 543u32]);
+        assert_eq!(ret, Ok(2));
+        assert_eq!(stack.pop(), Some(543u32));
+    }
+
+    #[test]
+    fn stack_push_all_exactly_to_capacity() {
+        let mut stack = Stack::new(3);
+
+        let ret = stack.push_all(vec![542u32, 543u32, 544u32]);
+        assert_eq!(ret, Ok(3));
+        assert!(stack.is_full());
+
+        let ret = stack.push_all(Vec::new());
+        assert_eq!(ret, Ok(0));
+    }
+
+    #[test]
+    fn stack_push_all_past_capacity_returns_error() {
+        let mut stack = Stack::new(3);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        let ret = stack.push_all(vec![543u32, 544u32, 545u32]);
+        assert_eq!(ret, Err((2, StackFullError)));
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(544u32));
+    }
+
+    #[test]
+    fn stack_try_from_iter_underfills() {
+        let ret = Stack::try_from_iter(5, vec![542u32, 543u32]);
+
+        let mut stack = ret.ok().unwrap();
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.capacity(), 5);
+        assert_eq!(stack.pop(), Some(543u32));
+    }
+
+    #[test]
+    fn stack_try_from_iter_exactly_fills() {
+        let ret = Stack::try_from_iter(3, 0..3u32);
+
+        let mut stack = ret.ok().unwrap();
+        assert!(stack.is_full());
+        assert_eq!(stack.pop(), Some(2u32));
+    }
+
+    #[test]
+    fn stack_try_from_iter_overflows() {
+        let ret = Stack::try_from_iter(3, 0..10u32);
+
+        let (mut stack, unplaced) = ret.err().unwrap();
+        assert_eq!(unplaced, 7);
+        assert!(stack.is_full());
+        assert_eq!(stack.pop(), Some(2u32));
+        assert_eq!(stack.pop(), Some(1u32));
+        assert_eq!(stack.pop(), Some(0u32));
+    }
+
+    #[test]
+    fn stack_try_from_iter_zero_capacity() {
+        let ret = Stack::try_from_iter(0, 0..2u32);
+
+        let (stack, unplaced) = ret.err().unwrap();
+        assert_eq!(unplaced, 2);
+        assert!(stack.is_empty());
+
+        let ret = Stack::<u32>::try_from_iter(0, Vec::new());
+        assert!(ret.is_ok());
+    }
+
+    #[test]
+    fn stack_peek_nth_zero_is_top() {
+        let mut stack = Stack::new(5);
+
+        let mut ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(stack.peek_nth(0), stack.peek());
+        assert_eq!(stack.peek_nth(0), Some(&543u32));
+    }
+
+    #[test]
+    fn stack_peek_nth_middle() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32, 545u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.peek_nth(1), Some(&544u32));
+        assert_eq!(stack.peek_nth(2), Some(&543u32));
+        assert_eq!(stack.peek_nth(3), Some(&542u32));
+        assert_eq!(stack.len(), 4);
+    }
+
+    #[test]
+    fn stack_peek_nth_out_of_range_returns_none() {
+        let mut stack = Stack::new(5);
+        assert_eq!(stack.peek_nth(0), None);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(stack.peek_nth(1), None);
+        assert_eq!(stack.peek_nth(usize::MAX), None);
+    }
+
+    #[test]
+    fn stack_default_is_empty_and_full() {
+        let mut stack = Stack::<u32>::default();
+
+        assert_eq!(stack.len(), 0);
+        assert_eq!(stack.capacity(), 0);
+        assert!(stack.is_full());
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Err(StackFullError));
+    }
+
+    #[test]
+    fn stack_index_reads_bottom_to_top() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack[0], 542u32);
+        assert_eq!(stack[1], 543u32);
+        assert_eq!(stack[2], 544u32);
+        assert_eq!(Some(&stack[2]), stack.peek());
+    }
+
+    #[test]
+    fn stack_index_mut_writes_in_place() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        stack[0] = 1u32;
+        stack[2] += 1;
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(545u32));
+        assert_eq!(stack.pop(), Some(543u32));
+        assert_eq!(stack.pop(), Some(1u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 1 out of range for stack of length 1")]
+    fn stack_index_out_of_range_should_panic() {
+        let mut stack = Stack::new(5);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        let _ = stack[1];
+    }
+
+    #[test]
+    fn stack_truncate_drops_top_elements() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32, 545u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        stack.truncate(2);
+
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.capacity(), 5);
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&542u32, &543u32]);
+    }
+
+    #[test]
+    fn stack_truncate_to_len_is_noop() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        stack.truncate(3);
+        assert_eq!(stack.len(), 3);
+
+        stack.truncate(10);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.capacity(), 5);
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&542u32, &543u32, &544u32]);
+    }
+
+    #[test]
+    fn stack_truncate_to_zero_empties() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        stack.truncate(0);
+
+        assert_eq!(stack.len(), 0);
+        assert!(stack.is_empty());
+        assert_eq!(stack.capacity(), 5);
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn stack_reverse_flips_pop_order() {
+        let mut stack = Stack::new(5);
+
+        for val in [1u32, 2u32, 3u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        stack.reverse();
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.capacity(), 5);
+        assert_eq!(stack.pop(), Some(1u32));
+        assert_eq!(stack.pop(), Some(2u32));
+        assert_eq!(stack.pop(), Some(3u32));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn stack_reverse_empty_and_single_is_noop() {
+        let mut stack = Stack::<u32>::new(5);
+
+        stack.reverse();
+        assert!(stack.is_empty());
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        stack.reverse();
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.pop(), Some(542u32));
+    }
+
+    #[test]
+    fn stack_extract_if_removes_matching() {
+        let mut stack = Stack::new(5);
+
+        for val in [1u32, 2u32, 3u32, 4u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let evens = stack.extract_if(|x| x % 2 == 0);
+        assert_eq!(evens, vec![2u32, 4u32]);
+
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.capacity(), 5);
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&1u32, &3u32]);
+    }
+
+    #[test]
+    fn stack_extract_if_no_match_leaves_stack_untouched() {
+        let mut stack = Stack::new(5);
+
+        for val in [1u32, 3u32, 5u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let evens = stack.extract_if(|x| x % 2 == 0);
+        assert!(evens.is_empty());
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&1u32, &3u32, &5u32]);
+    }
+
+    #[test]
+    fn stack_split_off_middle() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32, 545u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut top = stack.split_off(2);
+
+        assert_eq!(stack.len(), 2);
+        assert_eq!(top.len(), 2);
+        assert!(top.capacity() >= 2);
+
+        assert_eq!(top.pop(), Some(545u32));
+        assert_eq!(top.pop(), Some(544u32));
+        assert_eq!(top.pop(), None);
+
+        assert_eq!(stack.pop(), Some(543u32));
+        assert_eq!(stack.pop(), Some(542u32));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn stack_split_off_at_zero_moves_everything() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let top = stack.split_off(0);
+
+        assert!(stack.is_empty());
+        assert_eq!(stack.capacity(), 5);
+
+        let items: Vec<&u32> = top.iter().collect();
+        assert_eq!(items, vec![&542u32, &543u32, &544u32]);
+    }
+
+    #[test]
+    fn stack_split_off_at_len_moves_nothing() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let mut top = stack.split_off(3);
+
+        assert!(top.is_empty());
+        assert_eq!(top.pop(), None);
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(544u32));
+    }
+
+    #[test]
+    fn stack_pop_n_fewer_than_len() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32, 545u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.pop_n(2), vec![545u32, 544u32]);
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.capacity(), 5);
+        assert_eq!(stack.pop(), Some(543u32));
+    }
+
+    #[test]
+    fn stack_pop_n_exactly_len() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.pop_n(3), vec![544u32, 543u32, 542u32]);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn stack_pop_n_more_than_len_returns_available() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.pop_n(10), vec![543u32, 542u32]);
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop_n(1), Vec::<u32>::new());
+        assert_eq!(stack.pop_n(0), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn stack_push_checked_warns_past_depth() {
+        let mut stack = Stack::new(5);
+        stack.set_warn_depth(3);
+
+        for val in [542u32, 543u32, 544u32] {
+            assert_eq!(stack.push_checked(val), Ok(false));
+        }
+
+        assert_eq!(stack.push_checked(545u32), Ok(true));
+        assert_eq!(stack.push_checked(546u32), Ok(true));
+
+        // the hard limit is still capacity
+        assert_eq!(stack.push_checked(547u32), Err(StackFullError));
+        assert_eq!(stack.push(547u32), Err(StackFullError));
+
+        stack.pop();
+        stack.pop();
+        assert_eq!(stack.push_checked(545u32), Ok(true));
+
+        stack.pop();
+        stack.pop();
+        assert_eq!(stack.push_checked(544u32), Ok(false));
+    }
+
+    #[test]
+    fn stack_push_checked_without_warn_depth() {
+        let mut stack = Stack::new(2);
+
+        assert_eq!(stack.push_checked(542u32), Ok(false));
+        assert_eq!(stack.push_checked(543u32), Ok(false));
+        assert_eq!(stack.push_checked(544u32), Err(StackFullError));
+    }
+
+    #[test]
+    fn stack_warn_depth_zero_and_at_capacity() {
+        let mut stack = Stack::new(2);
+        stack.set_warn_depth(0);
+        assert_eq!(stack.push_checked(542u32), Ok(true));
+
+        let mut stack = Stack::new(2);
+        stack.set_warn_depth(2);
+        assert_eq!(stack.push_checked(542u32), Ok(false));
+        assert_eq!(stack.push_checked(543u32), Ok(false));
+        assert_eq!(stack.push_checked(544u32), Err(StackFullError));
+    }
+
+    fn capacities_after_pushes(mut stack: Stack<u32>, pushes: u32) -> Vec<usize> {
+        let mut capacities = Vec::new();
+
+        for i in 0..pushes {
+            let ret = stack.push(i);
+            assert_eq!(ret, Ok(()));
+
+            if capacities.last() != Some(&stack.capacity()) {
+                capacities.push(stack.capacity());
+            }
+        }
+
+        capacities
+    }
+
+    #[test]
+    fn stack_growth_policy_double() {
+        let stack = Stack::new_growable_with(2, GrowthPolicy::Double);
+        assert_eq!(capacities_after_pushes(stack, 17), vec![2, 4, 8, 16, 32]);
+
+        let stack = Stack::new_growable();
+        assert_eq!(capacities_after_pushes(stack, 5), vec![1, 2, 4, 8]);
+    }
+
+    #[test]
+    fn stack_growth_policy_fixed() {
+        let stack = Stack::new_growable_with(2, GrowthPolicy::Fixed(3));
+        assert_eq!(capacities_after_pushes(stack, 9), vec![2, 5, 8, 11]);
+
+        let stack = Stack::new_growable_with(0, GrowthPolicy::Fixed(0));
+        assert_eq!(capacities_after_pushes(stack, 3), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn stack_growth_policy_one_point_five() {
+        let stack = Stack::new_growable_with(4, GrowthPolicy::OnePointFive);
+        assert_eq!(capacities_after_pushes(stack, 14), vec![4, 6, 9, 13, 19]);
+
+        let stack = Stack::new_growable_with(0, GrowthPolicy::OnePointFive);
+        assert_eq!(capacities_after_pushes(stack, 4), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn stack_growth_policy_kept_by_clone() {
+        let stack = Stack::<u32>::new_growable_with(1, GrowthPolicy::Fixed(2));
+        let cloned = stack.clone();
+
+        assert_eq!(capacities_after_pushes(cloned, 4), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn stack_reserve_exact_raises_limit_to_capacity() {
+        let mut stack = Stack::new(5);
+
+        for i in 0..5u32 {
+            let ret = stack.push(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert!(stack.is_full());
+
+        stack.reserve_exact(3);
+        assert!(stack.capacity() >= 8);
+
+        // the new limit is whatever capacity the allocator provided
+        for i in 5..stack.capacity() as u32 {
+            let ret = stack.push(i);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.push(542u32), Err(StackFullError));
+        assert_eq!(stack.len(), stack.capacity());
+    }
+
+    #[test]
+    fn stack_reserve_exact_with_room_is_noop() {
+        let mut stack = Stack::new(5);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        stack.reserve_exact(2);
+        assert_eq!(stack.capacity(), 5);
+    }
+
+    #[test]
+    fn stack_pop_if_matching_top_pops() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.pop_if(|x| x % 2 == 1), Some(543u32));
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.peek(), Some(&542u32));
+    }
+
+    #[test]
+    fn stack_pop_if_non_matching_top_is_untouched() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // only the top is checked, even though 542 would match
+        assert_eq!(stack.pop_if(|x| x % 2 == 0), None);
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.peek(), Some(&543u32));
+    }
+
+    #[test]
+    fn stack_pop_if_when_empty_skips_predicate() {
+        let mut stack = Stack::<u32>::new(5);
+        let mut called = false;
+
+        assert_eq!(stack.pop_if(|_| { called = true; true }), None);
+        assert!(!called);
+    }
+
+    #[test]
+    fn stack_from_array_is_full() {
+        let mut stack = Stack::from([542u32, 543u32, 544u32]);
+
+        assert_eq!(stack.capacity(), 3);
+        assert_eq!(stack.push(545u32), Err(StackFullError));
+
+        assert_eq!(stack.pop(), Some(544u32));
+        assert_eq!(stack.pop(), Some(543u32));
+        assert_eq!(stack.pop(), Some(542u32));
+        assert_eq!(stack.pop(), None);
+
+        let mut stack = Stack::from([542u32]);
+        assert_eq!(stack.capacity(), 1);
+        assert_eq!(stack.pop(), Some(542u32));
+    }
+
+    #[test]
+    fn stack_from_empty_array() {
+        let mut stack = Stack::<u32>::from([]);
+
+        assert!(stack.is_empty());
+        assert!(stack.is_full());
+        assert_eq!(stack.push(542u32), Err(StackFullError));
+    }
+
+    #[test]
+    fn stack_extend_from_slice_that_fits() {
+        let mut stack = Stack::new(5);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        let ret = stack.extend_from_slice(&[543u32, 544u32, 545u32, 546u32]);
+        assert_eq!(ret, Ok(()));
+        assert!(stack.is_full());
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&542u32, &543u32, &544u32, &545u32, &546u32]);
+    }
+
+    #[test]
+    fn stack_extend_from_slice_overflow_pushes_nothing() {
+        let mut stack = Stack::new(3);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        let ret = stack.extend_from_slice(&[543u32, 544u32, 545u32]);
+        assert_eq!(ret, Err(StackFullError));
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.peek(), Some(&542u32));
+
+        let mut stack = Stack::new_growable_with(1, GrowthPolicy::Double);
+        let ret = stack.extend_from_slice(&[543u32, 544u32, 545u32]);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(stack.len(), 3);
+    }
+
+    #[test]
+    fn stack_extend_from_empty_slice() {
+        let mut stack = Stack::new(1);
+
+        assert_eq!(stack.extend_from_slice(&[]), Ok(()));
+        assert!(stack.is_empty());
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        // a full stack still accepts an empty slice
+        assert_eq!(stack.extend_from_slice(&[]), Ok(()));
+        assert_eq!(stack.len(), 1);
+    }
+
+    #[test]
+    fn stack_swap_remove_middle_moves_top_down() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32, 545u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.swap_remove(1), Some(543u32));
+        assert_eq!(stack.len(), 3);
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&542u32, &545u32, &544u32]);
+    }
+
+    #[test]
+    fn stack_swap_remove_top() {
+        let mut stack = Stack::new(5);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.swap_remove(2), Some(544u32));
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&542u32, &543u32]);
+    }
+
+    #[test]
+    fn stack_swap_remove_out_of_range_returns_none() {
+        let mut stack = Stack::new(5);
+        assert_eq!(stack.swap_remove(0), None);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(stack.swap_remove(1), None);
+        assert_eq!(stack.swap_remove(usize::MAX), None);
+        assert_eq!(stack.len(), 1);
+    }
+
+    #[test]
+    fn stack_bottom_is_first_pushed() {
+        let mut stack = Stack::new(5);
+        assert_eq!(stack.bottom(), None);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(stack.bottom(), Some(&542u32));
+        }
+
+        stack.pop();
+        stack.pop();
+        assert_eq!(stack.bottom(), Some(&542u32));
+        assert_eq!(stack.bottom(), stack.peek());
+
+        stack.pop();
+        assert_eq!(stack.bottom(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn stack_io_write_that_fits() {
+        use std::io::Write;
+
+        let mut stack = Stack::new(5);
+
+        assert_eq!(stack.write(b"abc").unwrap(), 3);
+        assert!(stack.flush().is_ok());
+        assert_eq!(stack.len(), 3);
+
+        let items: Vec<&u8> = stack.iter().collect();
+        assert_eq!(items, vec![&b'a', &b'b', &b'c']);
+
+        // popping reverses the byte order
+        assert_eq!(stack.pop_n(3), b"cba".to_vec());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn stack_io_write_overflow_is_short() {
+        use std::io::{ErrorKind, Write};
+
+        let mut stack = Stack::new(3);
+
+        assert_eq!(stack.write(b"abcde").unwrap(), 3);
+        assert!(stack.is_full());
+        assert_eq!(stack.write(b"f").unwrap(), 0);
+
+        let mut stack = Stack::new(3);
+        let err = stack.write_all(b"abcde").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&b'c'));
+    }
+
+    #[test]
+    fn stack_dedup_collapses_runs() {
+        let mut stack = Stack::from([1u32, 1u32, 2u32, 3u32, 3u32, 3u32]);
+
+        stack.dedup();
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.capacity(), 6);
+        assert_eq!(stack.pop(), Some(3u32));
+        assert_eq!(stack.pop(), Some(2u32));
+        assert_eq!(stack.pop(), Some(1u32));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn stack_dedup_without_duplicates_is_unchanged() {
+        let mut stack = Stack::from([1u32, 2u32, 1u32]);
+
+        stack.dedup();
+
+        let items: Vec<&u32> = stack.iter().collect();
+        assert_eq!(items, vec![&1u32, &2u32, &1u32]);
+
+        let mut empty = Stack::<u32>::new(3);
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn stack_shrink_to_fit_lowers_memory_usage() {
+        let mut stack = Stack::new(100);
+
+        for val in [542u64, 543u64, 544u64] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let overhead = core::mem::size_of::<Stack<u64>>();
+        assert_eq!(stack.memory_usage(), overhead + 100 * 8);
+
+        stack.shrink_to_fit();
+        assert_eq!(stack.capacity(), stack.len());
+        assert_eq!(stack.memory_usage(), overhead + 3 * 8);
+
+        // the full threshold dropped to the current length
+        assert_eq!(stack.push(545u64), Err(StackFullError));
+    }
+
+    #[test]
+    fn stack_memory_usage_when_empty() {
+        let stack = Stack::<u64>::new(0);
+
+        assert_eq!(stack.memory_usage(), core::mem::size_of::<Stack<u64>>());
+    }
+
+    #[test]
+    fn array_stack_push_to_capacity_then_full() {
+        let mut stack = ArrayStack::<u32, 3>::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.capacity(), 3);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert!(stack.is_full());
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.push(545u32), Err(StackFullError));
+        assert_eq!(stack.peek(), Some(&544u32));
+    }
+
+    #[test]
+    fn array_stack_pops_in_lifo_order() {
+        let mut stack = ArrayStack::<u32, 4>::default();
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.peek(), None);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.pop(), Some(544u32));
+        assert_eq!(stack.pop(), Some(543u32));
+
+        let ret = stack.push(545u32);
+        assert_eq!(ret, Ok(()));
+
+        assert_eq!(stack.pop(), Some(545u32));
+        assert_eq!(stack.pop(), Some(542u32));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn array_stack_zero_capacity() {
+        let mut stack = ArrayStack::<u32, 0>::new();
+
+        assert!(stack.is_full());
+        assert_eq!(stack.push(542u32), Err(StackFullError));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn array_stack_drops_remaining_elements() {
+        use alloc::rc::Rc;
+
+        let tracker = Rc::new(());
+
+        let mut stack = ArrayStack::<Rc<()>, 4>::new();
+
+        for _ in 0..3 {
+            assert!(stack.push(Rc::clone(&tracker)).is_ok());
+        }
+
+        assert_eq!(Rc::strong_count(&tracker), 4);
+
+        // a popped value is owned by the caller and dropped here
+        drop(stack.pop());
+        assert_eq!(Rc::strong_count(&tracker), 3);
+
+        drop(stack);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn stack_try_new_with_absurd_size_is_err() {
+        // the size overflows before any allocation is attempted
+        let ret = Stack::<u32>::try_new(isize::MAX as usize);
+        assert_eq!(ret.unwrap_err(), StackAllocError);
+
+        let ret = Stack::<u64>::try_new(usize::MAX);
+        assert_eq!(ret.unwrap_err(), StackAllocError);
+    }
+
+    #[test]
+    fn stack_try_new_matches_new() {
+        let mut stack = Stack::<u32>::try_new(2).unwrap();
+
+        assert!(stack.is_empty());
+        assert_eq!(stack.capacity(), 2);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+        let ret = stack.push(543u32);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(stack.push(544u32), Err(StackFullError));
+
+        let stack = Stack::<u32>::try_new(0).unwrap();
+        assert!(stack.is_full());
+    }
+
+    #[test]
+    fn stack_into_sorted_vec_sorts_ascending() {
+        let mut stack = Stack::<u32>::new(5);
+
+        for val in [544u32, 542u32, 545u32, 543u32, 542u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        assert_eq!(stack.into_sorted_vec(), vec![542u32, 542u32, 543u32, 544u32, 545u32]);
+    }
+
+    #[test]
+    fn stack_into_sorted_vec_by_descending() {
+        let mut stack = Stack::<u32>::new(4);
+
+        for val in [543u32, 545u32, 542u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        let sorted = stack.into_sorted_vec_by(|a, b| b.cmp(a));
+        assert_eq!(sorted, vec![545u32, 544u32, 543u32, 542u32]);
+    }
+
+    #[test]
+    fn stack_into_sorted_vec_when_empty() {
+        let stack = Stack::<u32>::new(5);
+        assert!(stack.into_sorted_vec().is_empty());
+
+        let stack = Stack::<u32>::new(0);
+        assert!(stack.into_sorted_vec_by(|a, b| b.cmp(a)).is_empty());
+    }
+
+    #[test]
+    fn stack_growable_is_never_full() {
+        let mut stack = Stack::<u32>::new_growable_with(2, GrowthPolicy::Double);
+
+        for val in [542u32, 543u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+        }
+
+        // at capacity, but the next push grows instead of erroring
+        assert_eq!(stack.len(), stack.capacity());
+        assert!(!stack.is_full());
+        assert!(!Collection::is_full(&stack));
+        assert_eq!(stack.remaining(), 0);
+
+        let ret = stack.push(544u32);
+        assert_eq!(ret, Ok(()));
+        assert!(!stack.is_full());
+
+        let mut bounded = Stack::<u32>::new(1);
+        let ret = bounded.push(542u32);
+        assert_eq!(ret, Ok(()));
+        assert!(bounded.is_full());
+        assert!(Collection::is_full(&bounded));
+    }
+
+    #[test]
+    fn stack_push_all_when_full_reports_none_pushed() {
+        let mut stack = Stack::new(1);
+
+        let ret = stack.push(542u32);
+        assert_eq!(ret, Ok(()));
+
+        let ret = stack.push_all(vec![543u32]);
+        assert_eq!(ret, Err((0, StackFullError)));
+        assert_eq!(stack.pop(), Some(542u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn stack_allocate_max_isize_should_panic() {
+        // panic - trying to allocate too much memory
+        let stack = Stack::<u32>::new(isize::MAX as usize); 
+        
+        assert_eq!(stack.stack.capacity(), isize::MAX as usize);
+    }
+
+    #[test]
+    fn stack_remaining_plus_len_is_capacity() {
+        let mut stack = Stack::new(4);
+        assert_eq!(stack.remaining(), 4);
+
+        for val in [542u32, 543u32, 544u32] {
+            let ret = stack.push(val);
+            assert_eq!(ret, Ok(()));
+            assert_eq!(stack.remaining() + stack.len(), stack.capacity());
+        }
+
+        assert_eq!(stack.remaining(), 1);
+
+        stack.pop();
+        assert_eq!(stack.remaining(), 2);
+
+        stack.push_all([545u32, 546u32]).unwrap();
+        assert_eq!(stack.remaining(), 0);
+        assert!(stack.is_full());
+    }
+
+    #[test]
+    fn stack_equal_stacks_hash_equally() {
+        use std::collections::HashSet;
+
+        let mut a = Stack::new(3);
+        let mut b = Stack::new_growable();
+
+        for val in [542u32, 543u32] {
+            assert_eq!(a.push(val), Ok(()));
+        }
+
+        assert_eq!(b.push_all([542u32, 543u32, 544u32]), Ok(3));
+        b.pop();
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        set.insert(b);
+        assert_eq!(set.len(), 1);
+
+        set.insert(Stack::from_vec(vec![543u32, 542u32]));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn stack_ord_differs_at_one_position() {
+        let a = Stack::from([542u32, 543u32, 544u32]);
+        let b = Stack::from([542u32, 549u32, 544u32]);
+
+        assert!(a < b);
+        assert!(b > a);
+        assert_eq!(a.cmp(&b), Ordering::Less);
+
+        let mut c = Stack::new(10);
+        assert_eq!(c.push_all([542u32, 543u32, 544u32]), Ok(3));
+        assert_eq!(a.cmp(&c), Ordering::Equal);
+    }
+
+    #[test]
+    fn stack_ord_different_lengths() {
+        let short = Stack::from([542u32, 543u32]);
+        let long = Stack::from([542u32, 543u32, 0u32]);
+        let empty = Stack::<u32>::new(5);
+
+        assert!(short < long);
+        assert!(empty < short);
+
+        // a larger element earlier wins over length
+        let bigger = Stack::from([543u32]);
+        assert!(long < bigger);
+
+        let mut sorted = vec![bigger.clone(), long.clone(), empty.clone(), short.clone()];
+        sorted.sort();
+        assert_eq!(sorted, vec![empty, short, long, bigger]);
     }
 }
@@ -0,0 +1,250 @@
+
+use std::sync::{Condvar, Mutex, MutexGuard};
+use crate::queue::{Queue, QueueFullError};
+
+/// A bounded `Queue` of type `T` that can be shared between threads. 
+/// `enqueue` blocks while the queue is full and `dequeue` blocks while it 
+/// is empty, making it suitable for producer/consumer setups. Wrap it in 
+/// an `Arc` to share it.
+pub struct SyncQueue<T> {
+    queue: Mutex<Queue<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+impl<T> SyncQueue<T> {
+    /// Create a new `SyncQueue` with a max capacity of `size`. A 
+    /// `SyncQueue` with a capacity of zero can never accept a value, so 
+    /// `enqueue` on it blocks forever.
+    /// ```
+    /// use rsds::sync::SyncQueue;
+    /// 
+    /// // Empty SyncQueue capable of holding up to 5 u32 elements.
+    /// let q = SyncQueue::<u32>::new(5);
+    /// ```
+    pub fn new(size: usize) -> Self {
+        SyncQueue {
+            queue: Mutex::new(Queue::new(size)),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        }
+    }
+
+    /// Places a value at the end of the `SyncQueue`, blocking the current 
+    /// thread until there is room.
+    /// ```
+    /// use rsds::sync::SyncQueue;
+    /// 
+    /// let q = SyncQueue::<u32>::new(5);
+    /// 
+    /// q.enqueue(42u32);
+    /// assert_eq!(q.dequeue(), 42u32);
+    /// ```
+    pub fn enqueue(&self, val: T) {
+        let mut queue = self
+            .not_full
+            .wait_while(self.lock(), |q| q.is_full())
+            .unwrap_or_else(|e| e.into_inner());
+
+        // the wait guarantees there is room
+        let _ = queue.enqueue(val);
+        self.not_empty.notify_one();
+    }
+
+    /// Removes a value from the front of the `SyncQueue`, blocking the 
+    /// current thread until one is available.
+    /// ```
+    /// use rsds::sync::SyncQueue;
+    /// use std::sync::Arc;
+    /// use std::thread;
+    /// 
+    /// let q = Arc::new(SyncQueue::<u32>::new(5));
+    /// 
+    /// let producer = Arc::clone(&q);
+    /// thread::spawn(move || producer.enqueue(42u32));
+    /// 
+    /// assert_eq!(q.dequeue(), 42u32);
+    /// ```
+    pub fn dequeue(&self) -> T {
+        let mut queue = self
+            .not_empty
+            .wait_while(self.lock(), |q| q.is_empty())
+            .unwrap_or_else(|e| e.into_inner());
+
+        // the wait guarantees there is a value
+        let val = queue.dequeue().unwrap();
+        self.not_full.notify_one();
+
+        val
+    }
+
+    /// Places a value at the end of the `SyncQueue` if there is room or 
+    /// returns a `QueueFullError` immediately if full.
+    /// ```
+    /// use rsds::queue::QueueFullError;
+    /// use rsds::sync::SyncQueue;
+    /// 
+    /// let q = SyncQueue::<u32>::new(1);
+    /// 
+    /// assert_eq!(q.try_enqueue(1u32), Ok(()));
+    /// assert_eq!(q.try_enqueue(2u32), Err(QueueFullError));
+    /// ```
+    pub fn try_enqueue(&self, val: T) -> Result<(), QueueFullError> {
+        self.lock().enqueue(val)?;
+        self.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    /// Removes a value from the front of the `SyncQueue` as an `Option<T>`, 
+    /// returning `None` immediately if the `SyncQueue` is empty.
+    /// ```
+    /// use rsds::sync::SyncQueue;
+    /// 
+    /// let q = SyncQueue::<u32>::new(1);
+    /// 
+    /// assert_eq!(q.try_dequeue(), None);
+    /// 
+    /// q.enqueue(1u32);
+    /// assert_eq!(q.try_dequeue(), Some(1u32));
+    /// ```
+    pub fn try_dequeue(&self) -> Option<T> {
+        let val = self.lock().dequeue()?;
+        self.not_full.notify_one();
+
+        Some(val)
+    }
+
+    /// Returns the number of elements in the `SyncQueue` as a `usize`. 
+    /// Other threads may change this as soon as it is returned.
+    /// ```
+    /// use rsds::sync::SyncQueue;
+    /// 
+    /// let q = SyncQueue::<u32>::new(5);
+    /// 
+    /// q.enqueue(1u32);
+    /// assert_eq!(q.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the `SyncQueue` contains no elements.
+    /// ```
+    /// use rsds::sync::SyncQueue;
+    /// 
+    /// let q = SyncQueue::<u32>::new(5);
+    /// 
+    /// assert!(q.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns the max capacity of the `SyncQueue` as a `usize`.
+    /// ```
+    /// use rsds::sync::SyncQueue;
+    /// 
+    /// let q = SyncQueue::<u32>::new(5);
+    /// 
+    /// assert_eq!(q.capacity(), 5);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity()
+    }
+
+    /// Locks the inner `Queue`. A panic while holding the lock can not 
+    /// leave the `Queue` half updated, so a poisoned lock is recovered.
+    fn lock(&self) -> MutexGuard<'_, Queue<T>> {
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test_sync {
+    use crate::sync::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn sync_try_enqueue_and_try_dequeue_do_not_block() {
+        let queue = SyncQueue::new(2);
+
+        assert_eq!(queue.try_dequeue(), None);
+
+        assert_eq!(queue.try_enqueue(1u32), Ok(()));
+        assert_eq!(queue.try_enqueue(2u32), Ok(()));
+        assert_eq!(queue.try_enqueue(3u32), Err(QueueFullError));
+
+        assert_eq!(queue.try_dequeue(), Some(1u32));
+        assert_eq!(queue.try_dequeue(), Some(2u32));
+        assert_eq!(queue.try_dequeue(), None);
+    }
+
+    #[test]
+    fn sync_enqueue_blocks_until_room() {
+        let queue = Arc::new(SyncQueue::new(1));
+        queue.enqueue(1u32);
+
+        let producer = Arc::clone(&queue);
+        let handle = thread::spawn(move || producer.enqueue(2u32));
+
+        assert_eq!(queue.dequeue(), 1u32);
+        assert_eq!(queue.dequeue(), 2u32);
+
+        handle.join().unwrap();
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn sync_multiple_producers_and_consumers() {
+        const PRODUCERS: u32 = 4;
+        const CONSUMERS: u32 = 4;
+        const PER_PRODUCER: u32 = 1000;
+
+        let queue = Arc::new(SyncQueue::new(8));
+
+        let producers: Vec<_> = (0..PRODUCERS)
+            .map(|p| {
+                let queue = Arc::clone(&queue);
+
+                thread::spawn(move || {
+                    for i in 0..PER_PRODUCER {
+                        queue.enqueue(p * PER_PRODUCER + i);
+                    }
+                })
+            })
+            .collect();
+
+        let consumers: Vec<_> = (0..CONSUMERS)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+
+                thread::spawn(move || {
+                    let mut received = Vec::new();
+
+                    for _ in 0..(PRODUCERS * PER_PRODUCER / CONSUMERS) {
+                        received.push(queue.dequeue());
+                    }
+
+                    received
+                })
+            })
+            .collect();
+
+        for handle in producers {
+            handle.join().unwrap();
+        }
+
+        let mut received: Vec<u32> = consumers
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+
+        received.sort_unstable();
+
+        let expected: Vec<u32> = (0..PRODUCERS * PER_PRODUCER).collect();
+        assert_eq!(received, expected);
+        assert!(queue.is_empty());
+    }
+}